@@ -0,0 +1,255 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, Read, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use sudoku::game::{Board, SudokuError};
+
+// The ways the command can fail, each with its own exit code.
+#[derive(Debug)]
+pub enum CliError {
+    Usage(String),           // The arguments didn't make sense.
+    Read(io::Error),         // The input couldn't be read.
+    Write(io::Error),        // The output couldn't be written.
+    Parse(SudokuError),      // The input isn't a board.
+    Invalid(SudokuError),    // The board breaks the rules, or has too few clues to solve.
+    Unsolvable(SudokuError), // The board has no solution.
+    BatchFailures(usize),    // Some of the puzzles in a batch couldn't be solved.
+}
+
+impl CliError {
+    // The code the process should exit with.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CliError::Parse(_) => 2,
+            CliError::Invalid(_) => 3,
+            CliError::Unsolvable(_) => 4,
+            _ => 1,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(msg) => f.write_str(msg),
+            CliError::Read(err) => write!(f, "failed to read input: {}", err),
+            CliError::Write(err) => write!(f, "failed to write output: {}", err),
+            CliError::Parse(err) => write!(f, "failed to parse board: {}", err),
+            CliError::Invalid(err) => write!(f, "the provided board is invalid: {}", err),
+            CliError::Unsolvable(err) => write!(f, "unable to solve board: {}", err),
+            CliError::BatchFailures(n) => write!(f, "unable to solve {} of the puzzles", n),
+        }
+    }
+}
+
+// Run the command with the given arguments (excluding the program name), reading the puzzle
+// from stdin unless a file is named, and writing the result to stdout.
+pub fn run(
+    args: impl Iterator<Item = String>,
+    stdin: impl Read,
+    mut stdout: impl Write,
+) -> Result<(), CliError> {
+    let args = parse_args(args).map_err(CliError::Usage)?;
+    if let Some(clues) = args.generate {
+        let seed = args.seed.unwrap_or_else(clock_seed);
+        let puzzle = Board::generate_puzzle(seed, clues);
+        return stdout
+            .write_all(render(&puzzle, args.format).as_bytes())
+            .map_err(CliError::Write);
+    }
+    let buf = read_input(args.path.as_deref(), stdin).map_err(CliError::Read)?;
+    if args.batch {
+        return match solve_batch(buf.as_bytes(), stdout, args.solve).map_err(CliError::Write)? {
+            0 => Ok(()),
+            failures => Err(CliError::BatchFailures(failures)),
+        };
+    }
+
+    let mut board: Board = Board::parse(buf).map_err(|err| match err {
+        // Parsing places each value as it goes, so it catches duplicates too.
+        SudokuError::Clash { .. } => CliError::Invalid(err),
+        _ => CliError::Parse(err),
+    })?;
+    board.check().map_err(CliError::Invalid)?;
+    args.solve.apply(&mut board).map_err(|err| match err {
+        // Too few clues means too many solutions, not none.
+        SudokuError::Underdetermined { .. } => CliError::Invalid(err),
+        _ => CliError::Unsolvable(err),
+    })?;
+    stdout
+        .write_all(render(&board, args.format).as_bytes())
+        .map_err(CliError::Write)
+}
+
+// A seed for generation that differs from run to run.
+fn clock_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+// How the solved board is printed.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Format {
+    Line,  // A single line of digits, as accepted by parse (without the newlines).
+    Grid,  // A grid with the subsquares marked out.
+    Debug, // The Debug representation, including each cell's options.
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Result<Format, String> {
+        match name {
+            "line" => Ok(Format::Line),
+            "grid" => Ok(Format::Grid),
+            "debug" => Ok(Format::Debug),
+            _ => Err(format!(
+                "unknown format {:?}, wanted one of: line, grid, debug",
+                name
+            )),
+        }
+    }
+}
+
+// How the puzzle is solved, as set by --max-passes and --no-guess.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SolveConfig {
+    pub max_passes: usize, // The passes logic gets over the board before giving up.
+    pub guess: bool,       // Whether to guess once logic stalls, rather than stopping there.
+}
+
+impl Default for SolveConfig {
+    fn default() -> Self {
+        SolveConfig {
+            max_passes: Board::<9>::MAX_PASSES,
+            guess: true,
+        }
+    }
+}
+
+impl SolveConfig {
+    // Solve the board as configured.  Without guessing, the board may be left unsolved, with
+    // its remaining cells narrowed to their options.
+    pub fn apply(&self, board: &mut Board) -> Result<(), SudokuError> {
+        if self.guess {
+            board.solve_with_max_passes(self.max_passes)
+        } else {
+            board.solve_logical_with_max_passes(self.max_passes)
+        }
+    }
+}
+
+// The options given on the command line.
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub path: Option<String>, // The file to read the puzzle from, or None for stdin.
+    pub format: Format,
+    pub batch: bool,             // Whether to solve many puzzles, one per line.
+    pub generate: Option<usize>, // Generate a puzzle with this many clues, instead of solving one.
+    pub seed: Option<u64>,       // The seed for generation, or None to pick one from the clock.
+    pub solve: SolveConfig,
+}
+
+// The number of clues --generate aims for when it isn't given a number.
+const DEFAULT_CLUES: usize = 30;
+
+// Parse the command line arguments (excluding the program name):
+//   sudoku [--format line|grid|debug] [--batch] [--max-passes n] [--no-guess] [path/to/puzzle.txt]
+//   sudoku [--format line|grid|debug] --generate [clues] [--seed n]
+pub fn parse_args(args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut args = args.peekable();
+    let mut parsed = Args {
+        path: None,
+        format: Format::Grid,
+        batch: false,
+        generate: None,
+        seed: None,
+        solve: SolveConfig::default(),
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => match args.next() {
+                Some(name) => parsed.format = Format::parse(&name)?,
+                None => return Err("--format needs a value".to_string()),
+            },
+            "--batch" => parsed.batch = true,
+            "--generate" => {
+                // The number of clues is optional.
+                let clues = args.peek().and_then(|clues| clues.parse().ok());
+                if clues.is_some() {
+                    args.next();
+                }
+                parsed.generate = Some(clues.unwrap_or(DEFAULT_CLUES));
+            }
+            "--seed" => match args.next().map(|seed| seed.parse()) {
+                Some(Ok(seed)) => parsed.seed = Some(seed),
+                _ => return Err("--seed needs a number".to_string()),
+            },
+            "--max-passes" => match args.next().map(|passes| passes.parse()) {
+                Some(Ok(passes)) => parsed.solve.max_passes = passes,
+                _ => return Err("--max-passes needs a number".to_string()),
+            },
+            "--no-guess" => parsed.solve.guess = false,
+            flag if flag.starts_with("--") => return Err(format!("unknown flag {}", flag)),
+            _ if parsed.path.is_some() => return Err(format!("unexpected argument {}", arg)),
+            _ => parsed.path = Some(arg),
+        }
+    }
+    if parsed.generate.is_some() && (parsed.batch || parsed.path.is_some()) {
+        return Err("--generate can't be combined with reading a puzzle".to_string());
+    }
+    Ok(parsed)
+}
+
+// Read the puzzle from the file at path, or from stdin when no path is given.
+pub fn read_input(path: Option<&str>, mut stdin: impl Read) -> io::Result<String> {
+    match path {
+        Some(path) => fs::read_to_string(path),
+        None => {
+            let mut buf = String::new();
+            stdin.read_to_string(&mut buf)?;
+            Ok(buf)
+        }
+    }
+}
+
+// Solve each line of input as a separate single-line puzzle, writing one line of output per
+// line of input: the solution, or an error marker for puzzles that can't be solved.  Returns
+// the number of puzzles that couldn't be solved.
+pub fn solve_batch(
+    input: impl BufRead,
+    mut output: impl Write,
+    config: SolveConfig,
+) -> io::Result<usize> {
+    let mut failures = 0;
+    for line in input.lines() {
+        match solve_line(&line?, config) {
+            Ok(solution) => writeln!(output, "{}", solution)?,
+            Err(err) => {
+                failures += 1;
+                writeln!(output, "error: {}", err)?;
+            }
+        }
+    }
+    Ok(failures)
+}
+
+// Solve a single-line puzzle, returning the solution on a single line.
+fn solve_line(line: &str, config: SolveConfig) -> Result<String, SudokuError> {
+    let mut board: Board = Board::parse_line(line)?;
+    config.apply(&mut board)?;
+    Ok(board.to_line())
+}
+
+// Render the board in the given format, ending with a newline.
+pub fn render(board: &Board, format: Format) -> String {
+    match format {
+        Format::Line => format!("{}\n", board.to_line()),
+        Format::Grid => board.to_string(),
+        Format::Debug => format!("{:#?}\n", board),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,321 @@
+use super::*;
+
+const PUZZLE: &str = "\
+005300000
+800000020
+070010500
+400005300
+010070006
+003200080
+060500009
+004000030
+000009700
+";
+
+#[test]
+fn check_read_stdin() {
+    let input = read_input(None, PUZZLE.as_bytes()).expect("reading stdin");
+    assert_eq!(input, PUZZLE);
+}
+
+#[test]
+fn check_read_file() {
+    // The process id keeps concurrent runs from sharing the file.
+    let name = format!("sudoku-check-read-file-{}.txt", std::process::id());
+    let path = std::env::temp_dir().join(name);
+    fs::write(&path, PUZZLE).expect("writing puzzle file");
+
+    // The file wins over stdin. It is removed before checking so a failure doesn't leave it behind.
+    let input = read_input(path.to_str(), "ignored".as_bytes());
+    fs::remove_file(&path).expect("removing puzzle file");
+    assert_eq!(input.expect("reading file"), PUZZLE);
+}
+
+#[test]
+fn check_read_missing_file() {
+    if let Ok(input) = read_input(Some("/does/not/exist.txt"), PUZZLE.as_bytes()) {
+        panic!("wanted error reading missing file, got: {:?}", input)
+    }
+}
+
+fn args(args: &[&str]) -> Result<Args, String> {
+    parse_args(args.iter().map(|arg| arg.to_string()))
+}
+
+#[test]
+fn check_parse_args() {
+    assert_eq!(
+        args(&[]),
+        Ok(Args {
+            path: None,
+            format: Format::Grid,
+            batch: false,
+            generate: None,
+            seed: None,
+            solve: SolveConfig::default(),
+        })
+    );
+    assert_eq!(
+        args(&["--format", "line", "puzzle.txt"]),
+        Ok(Args {
+            path: Some("puzzle.txt".to_string()),
+            format: Format::Line,
+            batch: false,
+            generate: None,
+            seed: None,
+            solve: SolveConfig::default(),
+        })
+    );
+    assert_eq!(
+        args(&["puzzle.txt", "--format", "debug"]),
+        Ok(Args {
+            path: Some("puzzle.txt".to_string()),
+            format: Format::Debug,
+            batch: false,
+            generate: None,
+            seed: None,
+            solve: SolveConfig::default(),
+        })
+    );
+    assert_eq!(
+        args(&["--batch", "puzzles.txt"]),
+        Ok(Args {
+            path: Some("puzzles.txt".to_string()),
+            format: Format::Grid,
+            batch: true,
+            generate: None,
+            seed: None,
+            solve: SolveConfig::default(),
+        })
+    );
+}
+
+#[test]
+fn check_parse_generate_args() {
+    assert_eq!(
+        args(&["--generate"]),
+        Ok(Args {
+            path: None,
+            format: Format::Grid,
+            batch: false,
+            generate: Some(DEFAULT_CLUES),
+            seed: None,
+            solve: SolveConfig::default(),
+        })
+    );
+    assert_eq!(
+        args(&["--generate", "25", "--seed", "42"]),
+        Ok(Args {
+            path: None,
+            format: Format::Grid,
+            batch: false,
+            generate: Some(25),
+            seed: Some(42),
+            solve: SolveConfig::default(),
+        })
+    );
+}
+
+#[test]
+fn check_parse_solve_args() {
+    assert_eq!(
+        args(&["--max-passes", "50", "--no-guess", "puzzle.txt"]),
+        Ok(Args {
+            path: Some("puzzle.txt".to_string()),
+            format: Format::Grid,
+            batch: false,
+            generate: None,
+            seed: None,
+            solve: SolveConfig {
+                max_passes: 50,
+                guess: false,
+            },
+        })
+    );
+    assert_eq!(
+        args(&["--max-passes", "abc"]),
+        Err("--max-passes needs a number".to_string())
+    );
+    assert!(args(&["--max-passes"]).is_err());
+    assert_eq!(args(&["--guess"]), Err("unknown flag --guess".to_string()));
+}
+
+#[test]
+fn check_parse_bad_args() {
+    assert_eq!(
+        args(&["--format", "fancy"]),
+        Err("unknown format \"fancy\", wanted one of: line, grid, debug".to_string())
+    );
+    assert!(args(&["--format"]).is_err());
+    assert!(args(&["--fancy"]).is_err());
+    assert!(args(&["one.txt", "two.txt"]).is_err());
+    assert!(args(&["--seed", "abc"]).is_err());
+    assert!(args(&["--max-passes", "-1"]).is_err());
+    assert_eq!(
+        args(&["--generate", "puzzle.txt"]),
+        Err("--generate can't be combined with reading a puzzle".to_string())
+    );
+    assert!(args(&["--generate", "--batch"]).is_err());
+}
+
+#[test]
+fn check_render() {
+    let mut board: Board = Board::parse(PUZZLE.to_string()).expect("parsing puzzle");
+    board.solve().expect("error finding solution");
+
+    assert_eq!(
+        render(&board, Format::Line),
+        "145327698839654127672918543496185372218473956753296481367542819984761235521839764\n"
+    );
+    assert_eq!(render(&board, Format::Grid), board.to_string());
+    assert_eq!(render(&board, Format::Debug), format!("{:#?}\n", board));
+    assert!(render(&board, Format::Grid).starts_with("+-------+-------+-------+\n| 1 4 5 |"));
+}
+
+#[test]
+fn check_solve_batch() {
+    let input = "\
+005300000800000020070010500400005300010070006003200080060500009004000030000009700
+115300000800000020070010500400005300010070006003200080060500009004000030000009700
+00530000080000002007001050040000530001007000600320008006050000900400003000000970
+005300000800000020070010500400005300010070006003200080060500009004000030000009700
+";
+    let mut output = Vec::new();
+    let failures =
+        solve_batch(input.as_bytes(), &mut output, SolveConfig::default()).expect("solving batch");
+
+    // The bad puzzles are reported without stopping the run.
+    assert_eq!(failures, 2);
+    assert_eq!(
+        String::from_utf8(output).expect("output is utf-8"),
+        "\
+145327698839654127672918543496185372218473956753296481367542819984761235521839764
+error: (0, 1) = 1 clashes with the same value at (0, 0)
+error: line has 80 cells, wanted 81
+145327698839654127672918543496185372218473956753296481367542819984761235521839764
+"
+    );
+}
+
+// Run the command with the given arguments and stdin, returning what it printed.
+fn run_with(args: &[&str], stdin: &str) -> Result<String, CliError> {
+    let mut stdout = Vec::new();
+    run(
+        args.iter().map(|arg| arg.to_string()),
+        stdin.as_bytes(),
+        &mut stdout,
+    )?;
+    Ok(String::from_utf8(stdout).expect("output is utf-8"))
+}
+
+#[test]
+fn check_run() {
+    let output = run_with(&["--format", "line"], PUZZLE).expect("running");
+    assert_eq!(
+        output,
+        "145327698839654127672918543496185372218473956753296481367542819984761235521839764\n"
+    );
+}
+
+#[test]
+fn check_run_errors() {
+    let err = run_with(&["--fancy"], PUZZLE).expect_err("wanted usage error");
+    assert!(matches!(err, CliError::Usage(_)));
+    assert_eq!(err.exit_code(), 1);
+
+    let err = run_with(&["/does/not/exist.txt"], "").expect_err("wanted read error");
+    assert!(matches!(err, CliError::Read(_)));
+    assert_eq!(err.exit_code(), 1);
+
+    let err = run_with(&[], "005300000\n").expect_err("wanted parse error");
+    assert!(matches!(err, CliError::Parse(SudokuError::RowCount { .. })));
+    assert_eq!(err.exit_code(), 2);
+
+    let err = run_with(&[], &PUZZLE.replacen("0", "5", 1)).expect_err("wanted invalid error");
+    assert!(matches!(
+        err,
+        CliError::Invalid(SudokuError::Clash { value: 5, .. })
+    ));
+    assert_eq!(err.exit_code(), 3);
+
+    // Every rule is satisfied and there are enough clues, but the top-left cell can't hold
+    // anything: 1-6 are in its row and 7-9 are in its column.
+    let unsolvable = "\
+012345600
+700000000
+800000000
+900000000
+023456789
+000000000
+000000000
+000000000
+000000000
+";
+    let err = run_with(&[], unsolvable).expect_err("wanted unsolvable error");
+    assert!(matches!(err, CliError::Unsolvable(_)));
+    assert_eq!(err.exit_code(), 4);
+
+    // Too few clues is a bad puzzle, not one without a solution.
+    let underdetermined = "\
+123456780
+000000009
+000000000
+000000000
+000000000
+000000000
+000000000
+000000000
+000000000
+";
+    let err = run_with(&[], underdetermined).expect_err("wanted invalid error");
+    assert!(matches!(
+        err,
+        CliError::Invalid(SudokuError::Underdetermined {
+            clues: 9,
+            wanted: 17
+        })
+    ));
+    assert_eq!(err.exit_code(), 3);
+
+    let err = run_with(&["--batch"], "1\n").expect_err("wanted batch failure");
+    assert!(matches!(err, CliError::BatchFailures(1)));
+    assert_eq!(err.exit_code(), 1);
+}
+
+#[test]
+fn check_run_no_guess() {
+    // Logic alone stalls on the hardest puzzle, which leaves it unsolved rather than failing.
+    let mut board: Board = Board::parse(PUZZLE.to_string()).expect("parsing puzzle");
+    let config = SolveConfig {
+        guess: false,
+        ..SolveConfig::default()
+    };
+    config.apply(&mut board).expect("solving without guessing");
+    assert!(!board.is_complete());
+
+    let output = run_with(&["--no-guess", "--format", "line"], PUZZLE).expect("running");
+    assert_eq!(output, format!("{}\n", board.to_line()));
+    assert!(output.contains('0'));
+
+    // Too few passes for logic to stall is a failure to solve.
+    let err = run_with(&["--max-passes", "1"], PUZZLE).expect_err("wanted unsolvable error");
+    assert!(matches!(
+        err,
+        CliError::Unsolvable(SudokuError::DidNotConverge)
+    ));
+}
+
+#[test]
+fn check_run_generate() {
+    let output =
+        run_with(&["--generate", "30", "--seed", "7", "--format", "line"], "").expect("running");
+    let puzzle: Board = Board::parse_line(output.trim_end()).expect("parsing generated puzzle");
+    puzzle.check().expect("Failed to validate board.");
+    assert!(puzzle.has_unique_solution());
+    assert!(puzzle.count_filled() >= 30);
+
+    // The same seed produces the same puzzle.
+    let again =
+        run_with(&["--generate", "30", "--seed", "7", "--format", "line"], "").expect("running");
+    assert_eq!(output, again);
+}
@@ -1,9 +1,46 @@
 mod bitset;
+mod builder;
+mod cages;
+mod constraints;
+mod difficulty;
+mod error;
+#[cfg(all(test, feature = "std"))]
+mod fixtures;
+mod generate;
+mod hint;
+mod history;
+mod order;
+mod regions;
+mod render;
+mod solutions;
+mod strategy;
+mod transcript;
+mod transform;
+mod unit;
 
-use std::fmt;
-use std::result::Result;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::result::Result;
 
-#[derive(Copy, Clone, PartialEq)]
+pub use bitset::BitSet;
+pub use builder::BoardBuilder;
+pub use cages::{Cage, CageSums};
+pub use constraints::{Constraint, ExtraConstraints};
+pub use difficulty::{ClueProfile, Difficulty};
+pub use error::SudokuError;
+pub use generate::{Rng, XorShift};
+pub use hint::Hint;
+pub use history::Game;
+pub use order::ValueOrder;
+pub use solutions::Solutions;
+pub use strategy::{BoxLineReduction, HiddenSingles, NakedSingles, Strategy, Technique};
+pub use transcript::Move;
+pub use unit::Unit;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Cell {
     Value(usize),            // Holds the actual value.
     Options(bitset::BitSet), // Holds a bit-mask of availabile options
@@ -12,38 +49,129 @@ pub enum Cell {
 impl fmt::Debug for Cell {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Cell::Value(v) => return f.write_fmt(format_args!("\t{}", v)),
-            Cell::Options(opts) => return f.write_fmt(format_args!("\t{:?}", opts)),
+            Cell::Value(v) => f.write_fmt(format_args!("{}", v)),
+            Cell::Options(opts) => f.write_fmt(format_args!("{:?}", opts)),
         }
     }
 }
 
-#[derive(Copy, Clone, PartialEq)]
-pub struct Board {
-    cells: [[Cell; 9]; 9],
+// Where a board stands, as reported by status.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BoardStatus {
+    Empty,    // No cell holds a value.
+    Partial,  // Some, but not all, cells hold a value.
+    Complete, // Every cell holds a value: the board is solved.
+    Invalid,  // The values break the rules.
+}
+
+// A Board of N rows and N columns, holding the values 1 to N.  Unless it is built with other
+// regions, its subsquares are the boxes box_shape gives for N: square when N is a perfect
+// square, and otherwise as close to square as N allows, e.g. 2x3 on a 6x6 board.
+#[derive(Copy, Clone)]
+pub struct Board<const N: usize = 9> {
+    cells: [[Cell; N]; N],
+    givens: [[bool; N]; N], // Which cells held a value when the board was created.
+    regions: regions::Regions<N>, // The subsquares, or the irregular regions of a Jigsaw board.
+    constraints: ExtraConstraints, // Rules beyond the standard row, column, and subsquare ones.
+    cages: cages::Cages<N>, // The cages of a Killer Sudoku, if any.
+    sets: constraints::SetEquals<N>, // The cells that must hold the same values, if any.
+    // The values not yet placed in each row, column, and region, kept up to date by place so
+    // that the options of a cell don't require scanning its peers.
+    row_unplaced: [BitSet; N],
+    col_unplaced: [BitSet; N],
+    region_unplaced: [BitSet; N],
+}
+
+// The parts of a Board that change while solving, which is all that needs to be saved in
+// order to undo a guess.  The givens, regions, and constraints stay the same.
+#[derive(Copy, Clone)]
+struct Snapshot<const N: usize> {
+    cells: [[Cell; N]; N],
+    row_unplaced: [BitSet; N],
+    col_unplaced: [BitSet; N],
+    region_unplaced: [BitSet; N],
+}
+
+// Boards are equal when their cells are exactly, including the options of unsolved cells, but
+// regardless of which values were givens, so that a solved puzzle compares equal to its
+// solution.  Use same_values to ignore the options.
+impl<const N: usize> PartialEq for Board<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<const N: usize> Eq for Board<N> {}
+
+// Hash just the cells, to agree with ==.
+impl<const N: usize> Hash for Board<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.cells.hash(state);
+    }
 }
 
-impl fmt::Debug for Board {
+// Debug shows the raw cells, a row per line, padded so that the columns line up.  Solved cells
+// show their value and unsolved ones their options, e.g. [1269].
+impl<const N: usize> fmt::Debug for Board<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("\n")?;
-        for row in 0..9 {
-            f.write_fmt(format_args!("{:?}\n", self.cells[row]))?;
+        for row in self.cells.iter() {
+            for (cidx, cell) in row.iter().enumerate() {
+                let width = match cell {
+                    Cell::Value(v) => {
+                        write!(f, "{}", render::glyph(*v))?;
+                        1
+                    }
+                    Cell::Options(opts) => {
+                        f.write_str("[")?;
+                        for v in opts.foreach() {
+                            write!(f, "{}", render::glyph(v))?;
+                        }
+                        f.write_str("]")?;
+                        opts.count() + 2
+                    }
+                };
+                // Pad to the widest a cell can be, besides the last in the row.
+                if cidx + 1 < N {
+                    for _ in width..N + 3 {
+                        f.write_str(" ")?;
+                    }
+                }
+            }
+            f.write_str("\n")?;
         }
         Ok(())
     }
 }
 
+impl<const N: usize> TryFrom<[[usize; N]; N]> for Board<N> {
+    type Error = SudokuError;
+
+    fn try_from(values: [[usize; N]; N]) -> Result<Board<N>, SudokuError> {
+        Board::new(values)
+    }
+}
+
+// Boards convert from strings in any of the layouts that parse_grid accepts.
+impl<const N: usize> TryFrom<&str> for Board<N> {
+    type Error = SudokuError;
+
+    fn try_from(input: &str) -> Result<Board<N>, SudokuError> {
+        Board::parse_grid(input)
+    }
+}
+
 // These are different "views" of the board.
 mod views {
     // Iterate over a particular Row of the board.
-    pub struct Row<'a> {
-        board: &'a super::Board,
+    pub struct Row<'a, const N: usize> {
+        board: &'a super::Board<N>,
         row_index: usize,
         col_index: usize,
     }
 
-    impl Row<'_> {
-        pub fn new(b: &super::Board, idx: usize) -> Row {
+    impl<const N: usize> Row<'_, N> {
+        pub fn new(b: &super::Board<N>, idx: usize) -> Row<'_, N> {
             Row {
                 board: b,
                 row_index: idx,
@@ -52,12 +180,12 @@ mod views {
         }
     }
 
-    impl<'a> Iterator for Row<'a> {
+    impl<'a, const N: usize> Iterator for Row<'a, N> {
         type Item = super::Cell;
 
         fn next(&mut self) -> Option<super::Cell> {
             match self.col_index {
-                9 => None,
+                idx if idx == N => None,
                 _ => {
                     let c = self.board.cells[self.row_index][self.col_index];
                     self.col_index += 1;
@@ -68,14 +196,14 @@ mod views {
     }
 
     // Iterate over a particular Column of the board.
-    pub struct Column<'a> {
-        board: &'a super::Board,
+    pub struct Column<'a, const N: usize> {
+        board: &'a super::Board<N>,
         col_index: usize,
         row_index: usize,
     }
 
-    impl Column<'_> {
-        pub fn new(b: &super::Board, idx: usize) -> Column {
+    impl<const N: usize> Column<'_, N> {
+        pub fn new(b: &super::Board<N>, idx: usize) -> Column<'_, N> {
             Column {
                 board: b,
                 col_index: idx,
@@ -84,12 +212,12 @@ mod views {
         }
     }
 
-    impl<'a> Iterator for Column<'a> {
+    impl<'a, const N: usize> Iterator for Column<'a, N> {
         type Item = super::Cell;
 
         fn next(&mut self) -> Option<super::Cell> {
             match self.row_index {
-                9 => None,
+                idx if idx == N => None,
                 _ => {
                     let c = self.board.cells[self.row_index][self.col_index];
                     self.row_index += 1;
@@ -99,35 +227,70 @@ mod views {
         }
     }
 
-    // Iterate over a particular SubSquare of the board.
-    pub struct SubSquare<'a> {
-        board: &'a super::Board,
-        base_col: usize,
-        base_row: usize,
+    // Iterate over a particular Region of the board (a subsquare, unless the board is a Jigsaw).
+    pub struct Region<'a, const N: usize> {
+        board: &'a super::Board<N>,
+        region: usize,
+        index: usize,
+    }
+
+    impl<const N: usize> Region<'_, N> {
+        pub fn new(b: &super::Board<N>, region: usize) -> Region<'_, N> {
+            Region {
+                board: b,
+                region,
+                index: 0,
+            }
+        }
+    }
+
+    impl<'a, const N: usize> Iterator for Region<'a, N> {
+        type Item = super::Cell;
+
+        fn next(&mut self) -> Option<super::Cell> {
+            match self.index {
+                idx if idx == N => None,
+                _ => {
+                    let (ridx, cidx) = self.board.regions.cell(self.region, self.index);
+                    let c = self.board.cells[ridx][cidx];
+                    self.index += 1;
+                    Some(c)
+                }
+            }
+        }
+    }
+
+    // Iterate over one of the two main diagonals of the board: the one running from the top-left
+    // corner to the bottom-right, or (the anti-diagonal) from the top-right to the bottom-left.
+    pub struct Diagonal<'a, const N: usize> {
+        board: &'a super::Board<N>,
+        anti: bool,
         index: usize,
     }
 
-    impl SubSquare<'_> {
-        pub fn new(b: &super::Board, ss_ridx: usize, ss_cidx: usize) -> SubSquare {
-            SubSquare {
+    impl<const N: usize> Diagonal<'_, N> {
+        pub fn new(b: &super::Board<N>, anti: bool) -> Diagonal<'_, N> {
+            Diagonal {
                 board: b,
-                base_row: ss_ridx * 3,
-                base_col: ss_cidx * 3,
+                anti,
                 index: 0,
             }
         }
     }
 
-    impl<'a> Iterator for SubSquare<'a> {
+    impl<'a, const N: usize> Iterator for Diagonal<'a, N> {
         type Item = super::Cell;
 
         fn next(&mut self) -> Option<super::Cell> {
             match self.index {
-                9 => None,
+                idx if idx == N => None,
                 _ => {
-                    // Every 3 we wrap around to the next row.
-                    let (div, modulo) = (self.index / 3, self.index % 3);
-                    let c = self.board.cells[self.base_row + div][self.base_col + modulo];
+                    let col = if self.anti {
+                        N - 1 - self.index
+                    } else {
+                        self.index
+                    };
+                    let c = self.board.cells[self.index][col];
                     self.index += 1;
                     Some(c)
                 }
@@ -135,13 +298,13 @@ mod views {
         }
     }
 
-    pub fn check(it: impl Iterator<Item = super::Cell>) -> Result<(), String> {
+    pub fn check(it: impl Iterator<Item = super::Cell>) -> Result<(), super::SudokuError> {
         let mut mask = 0;
         for elt in it {
             if let super::Cell::Value(v) = elt {
                 let bit = 1 << (v - 1);
                 if mask & bit != 0 {
-                    return Err(format!("Multiple {} seen", v));
+                    return Err(super::SudokuError::Duplicate(v));
                 }
                 mask |= bit;
             }
@@ -149,239 +312,1345 @@ mod views {
         Ok(())
     }
 
-    pub fn mask(it: impl Iterator<Item = super::Cell>) -> super::bitset::BitSet {
-        let mut mask = super::bitset::BitSet::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    // The values 1 to N that don't appear in the view.
+    pub fn mask<const N: usize>(it: impl Iterator<Item = super::Cell>) -> super::bitset::BitSet {
+        let mut mask = super::bitset::BitSet::all(N);
         for elt in it {
             if let super::Cell::Value(v) = elt {
-                mask = mask.unset(v as usize);
+                mask = mask.unset(v);
             }
         }
         mask
     }
+}
 
-    pub fn frequency(it: impl Iterator<Item = super::Cell>, value: usize) -> usize {
-        let mut freq = 0;
+// The (rows, columns) of the subsquares on a board of size n: the largest number of rows no
+// more than the square root of n that divides it, with as many columns as that leaves.
+const fn box_shape(n: usize) -> (usize, usize) {
+    let mut rows = 1;
+    let mut size = 1;
+    while size * size <= n {
+        if n.is_multiple_of(size) {
+            rows = size;
+        }
+        size += 1;
+    }
+    (rows, n / rows)
+}
 
-        for elt in it {
-            match elt {
-                super::Cell::Value(v) => {
-                    if v == value {
-                        // There should only be one, but the availability masks may be slightly stale.
-                        freq += 1;
-                    }
-                }
-                super::Cell::Options(opts) => {
-                    if opts.has(value as usize) {
-                        freq += 1;
-                    }
+// Solve a puzzle given as a string, returning the solution on a single line as to_line
+// renders it.  The puzzle may be on one line or a grid, with or without separators, as
+// parse_grid accepts.  This doesn't touch stdin or stdout, so it's easy to wrap (e.g. for
+// WASM) or fuzz.
+pub fn solve_str(input: &str) -> Result<String, SudokuError> {
+    let mut board = Board::<9>::parse_grid(input)?;
+    board.solve()?;
+    Ok(board.to_line())
+}
+
+// Parse and classify each of many puzzles, as parse_grid and status do, pairing each result
+// with the index of its input so that one bad puzzle doesn't hold up the rest.  Parsing
+// rejects boards that break the rules, so those are reported as Invalid rather than an error.
+pub fn classify_many<'a>(
+    inputs: impl IntoIterator<Item = &'a str>,
+) -> Vec<(usize, Result<BoardStatus, SudokuError>)> {
+    inputs
+        .into_iter()
+        .enumerate()
+        .map(|(index, input)| {
+            let status = match Board::<9>::parse_grid(input) {
+                Ok(board) => Ok(board.status()),
+                Err(SudokuError::Clash { .. }) => Ok(BoardStatus::Invalid),
+                Err(err) => Err(err),
+            };
+            (index, status)
+        })
+        .collect()
+}
+
+// Parse the value of a single cell on a board of size n, where 0 means the cell is blank.
+fn parse_value(c: char, n: usize) -> Result<usize, SudokuError> {
+    match c {
+        // Accept space or 0 as a blank.
+        '0' | ' ' => Ok(0),
+        // Digits become a real value.
+        '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => Ok(c as usize - '0' as usize),
+        // On larger boards, letters follow the digits as glyph renders them, so A is 10 and G
+        // is 16, in either case.  Letters past the size of the board are an error.
+        _ => match c.to_digit(36) {
+            Some(v) if v >= 10 && v as usize <= n => Ok(v as usize),
+            _ => Err(SudokuError::InvalidCharacter(c)),
+        },
+    }
+}
+
+impl<const N: usize> Board<N> {
+    // The number of rows and columns of each subsquare, unless the board is built with other
+    // boxes or regions.
+    const BOX_ROWS: usize = box_shape(N).0;
+    const BOX_COLS: usize = box_shape(N).1;
+
+    // The fewest givens solve accepts before refusing to search: no standard 9x9 puzzle with
+    // fewer than 17 has a unique solution, nor a 4x4 one with fewer than 4.  Other sizes
+    // aren't guarded.
+    pub const MIN_CLUES: usize = match N {
+        4 => 4,
+        9 => 17,
+        _ => 0,
+    };
+
+    // The most passes the strategies get over the board before solve gives up on logic ever
+    // stalling or finishing, and reports DidNotConverge.
+    pub const MAX_PASSES: usize = 1000;
+
+    // The strategies used by solve(), in the order they are applied.
+    pub const DEFAULT_STRATEGIES: &'static [&'static dyn Strategy<N>] =
+        &[&NakedSingles, &HiddenSingles, &BoxLineReduction, &CageSums];
+
+    // The (row, column) of the subsquare holding the given cell in the default layout for N,
+    // counting subsquares from the top left.  This knows nothing of any particular board, so
+    // on a Jigsaw board, or one built with_boxes of another shape, it is not the cell's
+    // region; use box_at to ask the board itself.
+    pub fn box_of(row: usize, col: usize) -> (usize, usize) {
+        Self::box_in(row, col, Self::BOX_ROWS, Self::BOX_COLS)
+    }
+
+    // The (row, column) of the box holding the given cell on this board, as box_of gives it
+    // but for the board's own boxes, e.g. 3x2 ones from with_boxes.  Jigsaw boards have no
+    // boxes, so this is None for them.
+    pub fn box_at(&self, row: usize, col: usize) -> Option<(usize, usize)> {
+        self.regions
+            .box_shape()
+            .map(|(box_rows, box_cols)| Self::box_in(row, col, box_rows, box_cols))
+    }
+
+    // The (row, column) of the box holding the given cell, as box_of gives it, for boxes of
+    // box_rows by box_cols cells.
+    pub(super) fn box_in(
+        row: usize,
+        col: usize,
+        box_rows: usize,
+        box_cols: usize,
+    ) -> (usize, usize) {
+        (row / box_rows, col / box_cols)
+    }
+
+    pub fn new(values: [[usize; N]; N]) -> Result<Board<N>, SudokuError> {
+        Board::build(values, regions::Regions::standard())
+    }
+
+    // Create a board from rows collected at runtime, e.g. from a form.  There must be N rows
+    // of N values each, from 0 (blank) to N.
+    pub fn from_vec(rows: &[Vec<usize>]) -> Result<Board<N>, SudokuError> {
+        Board::from_rows_iter(rows.iter().map(|row| row.iter().copied()))
+    }
+
+    // Create a board from any nested iterable of rows, e.g. an array decoded from JSON,
+    // checking the shape and range of the values as they are consumed.  There must be N rows
+    // of N values each, from 0 (blank) to N.
+    pub fn from_rows_iter<I, J>(rows: I) -> Result<Board<N>, SudokuError>
+    where
+        I: IntoIterator<Item = J>,
+        J: IntoIterator<Item = usize>,
+    {
+        let mut values = [[0; N]; N];
+        let mut rows = rows.into_iter();
+        for (ridx, slots) in values.iter_mut().enumerate() {
+            let row = match rows.next() {
+                Some(row) => row,
+                None => {
+                    return Err(SudokuError::RowCount {
+                        rows: ridx,
+                        wanted: N,
+                    })
                 }
             };
+            let mut columns = 0;
+            for (cidx, value) in row.into_iter().enumerate() {
+                columns += 1;
+                if cidx >= N {
+                    continue; // Keep counting, so that we can report the length.
+                }
+                if value > N {
+                    return Err(SudokuError::InvalidValue {
+                        row: ridx,
+                        col: cidx,
+                        value,
+                    });
+                }
+                slots[cidx] = value;
+            }
+            if columns != N {
+                return Err(SudokuError::ColumnCount {
+                    row: ridx,
+                    columns,
+                    wanted: N,
+                });
+            }
+        }
+        let extra = rows.count();
+        if extra != 0 {
+            return Err(SudokuError::RowCount {
+                rows: N + extra,
+                wanted: N,
+            });
         }
-        freq
+        Board::new(values)
+    }
+
+    // Create a board from a flat slice of N*N values in row-major order, from 0 (blank) to N,
+    // e.g. a buffer handed over FFI.  Bad values are reported by their index in the slice.
+    pub fn from_slice(values: &[usize]) -> Result<Board<N>, SudokuError> {
+        if values.len() != N * N {
+            return Err(SudokuError::CellCount {
+                cells: values.len(),
+                wanted: N * N,
+            });
+        }
+
+        let mut raw_board = [[0; N]; N];
+        for (index, &value) in values.iter().enumerate() {
+            if value > N {
+                return Err(SudokuError::InvalidEntry { index, value });
+            }
+            raw_board[index / N][index % N] = value;
+        }
+        Board::new(raw_board)
     }
-}
 
-impl Board {
-    pub fn new(values: [[usize; 9]; 9]) -> Result<Board, String> {
-        let all = bitset::BitSet::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    fn build(
+        values: [[usize; N]; N],
+        regions: regions::Regions<N>,
+    ) -> Result<Board<N>, SudokuError> {
+        let all = bitset::BitSet::all(N);
         let mut board = Board {
-            cells: [[Cell::Options(all); 9]; 9],
+            cells: [[Cell::Options(all); N]; N],
+            givens: [[false; N]; N],
+            regions,
+            constraints: ExtraConstraints::default(),
+            row_unplaced: [all; N],
+            col_unplaced: [all; N],
+            region_unplaced: [all; N],
+            cages: cages::Cages::none(),
+            sets: constraints::SetEquals::none(),
         };
 
-        for i in 0..9 {
-            for j in 0..9 {
-                let c = values[i][j];
-                if c > 9 {
-                    return Err(format!("Invalid value ({}, {}) = {}", i, j, c));
+        for (i, row) in values.iter().enumerate() {
+            for (j, &c) in row.iter().enumerate() {
+                if c > N {
+                    return Err(SudokuError::InvalidValue {
+                        row: i,
+                        col: j,
+                        value: c,
+                    });
                 }
                 if c == 0 {
                     continue; // 0 is used for unspecified, so leave "all" options.
                 }
-                board.set(i, j, Cell::Value(c))?;
+                board.put(i, j, Cell::Value(c));
+                board.givens[i][j] = true;
             }
         }
 
+        // Check the rules once everything is in place, rather than after each value.
+        board.check().map_err(|err| board.clash().unwrap_or(err))?;
         Ok(board)
     }
 
-    pub fn parse(input: String) -> Result<Board, String> {
+    // Find the first value, reading left to right and top to bottom, that is the same as the
+    // value of an earlier peer, to say exactly where a board breaks the rules.
+    fn clash(&self) -> Option<SudokuError> {
+        let cells = (0..N).flat_map(|ridx| (0..N).map(move |cidx| (ridx, cidx)));
+        for (idx, (ridx, cidx)) in cells.clone().enumerate() {
+            let value = match self.cells[ridx][cidx] {
+                Cell::Value(value) => value,
+                Cell::Options(_) => continue,
+            };
+            let peer = cells.clone().take(idx).find(|&(peer_ridx, peer_cidx)| {
+                self.cells[peer_ridx][peer_cidx] == Cell::Value(value)
+                    && self.is_peer(ridx, cidx, peer_ridx, peer_cidx)
+            });
+            if let Some((peer_row, peer_col)) = peer {
+                return Some(SudokuError::Clash {
+                    row: ridx,
+                    col: cidx,
+                    value,
+                    peer_row,
+                    peer_col,
+                });
+            }
+        }
+        None
+    }
+
+    // Parse a board with one line of N characters per row.  Empty lines before and after the
+    // board, as often come along when copying and pasting, are ignored.
+    pub fn parse(input: String) -> Result<Board<N>, SudokuError> {
+        let rows = input.trim_matches('\n').split('\n').collect::<Vec<&str>>();
+        if rows.len() != N {
+            // Blame stray whitespace over the count, since that is the likelier culprit.  A
+            // line of exactly N spaces is a row of blanks, so it doesn't count.
+            if let Some(row) = rows
+                .iter()
+                .position(|row| row.len() != N && row.trim().is_empty())
+            {
+                return Err(SudokuError::BlankLine(row));
+            }
+            return Err(SudokuError::RowCount {
+                rows: rows.len(),
+                wanted: N,
+            });
+        }
+
+        let mut raw_board = [[0; N]; N];
+        for (i, row) in rows.iter().enumerate() {
+            if row.len() != N {
+                return Err(SudokuError::ColumnCount {
+                    row: i,
+                    columns: row.len(),
+                    wanted: N,
+                });
+            }
+            for (j, c) in row.chars().enumerate() {
+                raw_board[i][j] = parse_value(c, N)?;
+            }
+        }
+        Board::new(raw_board)
+    }
+
+    // Read a board from r (e.g. a file or socket) and parse it as parse does.
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: std::io::Read>(mut r: R) -> Result<Board<N>, SudokuError> {
+        let mut input = String::new();
+        r.read_to_string(&mut input)
+            .map_err(|err| SudokuError::Read(err.kind()))?;
+        Board::parse(input)
+    }
+
+    // Parse a board as parse does, skipping comment lines (starting with '#') and empty lines,
+    // as in a file of annotated puzzles.  A line of N spaces is still a row of blanks.
+    pub fn parse_annotated(input: &str) -> Result<Board<N>, SudokuError> {
         let rows = input
-            .trim_end_matches("\n")
-            .split("\n")
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
             .collect::<Vec<&str>>();
-        if rows.len() != 9 {
-            return Err(format!("input has {} rows, wanted 9", rows.len()));
+        Board::parse(rows.join("\n"))
+    }
+
+    // Parse a board as parse does, but treat missing trailing rows, and missing cells at the
+    // end of a row, as blanks.  This only pads: more than N rows, or more than N cells in a
+    // row, is still an error.
+    pub fn parse_lenient(input: &str) -> Result<Board<N>, SudokuError> {
+        let rows = if input.is_empty() {
+            Vec::new()
+        } else {
+            input
+                .trim_end_matches("\n")
+                .split("\n")
+                .collect::<Vec<&str>>()
+        };
+        if rows.len() > N {
+            return Err(SudokuError::RowCount {
+                rows: rows.len(),
+                wanted: N,
+            });
         }
 
-        let mut raw_board = [[0; 9]; 9];
-        let mut i = 0;
-        for row in rows {
-            if row.len() != 9 {
-                return Err(format!("row {} has {} columns, wanted 9", row, row.len()));
+        let mut raw_board = [[0; N]; N];
+        for (i, row) in rows.iter().enumerate() {
+            if row.len() > N {
+                return Err(SudokuError::ColumnCount {
+                    row: i,
+                    columns: row.len(),
+                    wanted: N,
+                });
             }
             for (j, c) in row.chars().enumerate() {
-                match c {
-                    // Accept space or 0 as a blank.
-                    '0' | ' ' => continue,
-                    // Digits become a real value.
-                    '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
-                        raw_board[i][j] = c as usize - '0' as usize;
+                raw_board[i][j] = parse_value(c, N)?;
+            }
+        }
+        Board::new(raw_board)
+    }
+
+    // Parse a board written on a single line of N*N characters, reading left to right and top
+    // to bottom, with the same characters that parse accepts.
+    pub fn parse_line(line: &str) -> Result<Board<N>, SudokuError> {
+        Board::new(Self::line_values(line)?)
+    }
+
+    // The values of a board written on a single line, as parse_line reads them, without
+    // checking the rules.
+    fn line_values(line: &str) -> Result<[[usize; N]; N], SudokuError> {
+        let length = line.chars().count();
+        if length != N * N {
+            return Err(SudokuError::LineLength {
+                length,
+                wanted: N * N,
+            });
+        }
+
+        let mut raw_board = [[0; N]; N];
+        for (idx, c) in line.chars().enumerate() {
+            raw_board[idx / N][idx % N] = parse_value(c, N)?;
+        }
+        Ok(raw_board)
+    }
+
+    // Parse a board laid out as a grid with its subsquares marked out, as printed by Display:
+    //
+    //   +-------+-------+-------+
+    //   | 5 3 . | . 7 . | . . . |
+    //   ...
+    //
+    // The separators ('|', '+', and '-') and whitespace are ignored, and the remaining
+    // characters are read as cells left to right and top to bottom.  These may be '.' or '0'
+    // for a blank, or a digit.
+    pub fn parse_grid(input: &str) -> Result<Board<N>, SudokuError> {
+        let values = input
+            .chars()
+            .filter(|c| !c.is_whitespace() && !matches!(c, '|' | '+' | '-'))
+            .map(|c| if c == '.' { Ok(0) } else { parse_value(c, N) })
+            .collect::<Result<Vec<usize>, SudokuError>>()?;
+        if values.len() != N * N {
+            return Err(SudokuError::CellCount {
+                cells: values.len(),
+                wanted: N * N,
+            });
+        }
+
+        let mut raw_board = [[0; N]; N];
+        for (idx, value) in values.into_iter().enumerate() {
+            raw_board[idx / N][idx % N] = value;
+        }
+        Board::new(raw_board)
+    }
+
+    // Restore a board from the state to_state renders, including which values were given and
+    // the options of the unsolved cells.  Any whitespace separates the cells.
+    pub fn from_state(input: &str) -> Result<Board<N>, SudokuError> {
+        let tokens = input.split_whitespace().collect::<Vec<&str>>();
+        if tokens.len() != N * N {
+            return Err(SudokuError::CellCount {
+                cells: tokens.len(),
+                wanted: N * N,
+            });
+        }
+        let value = |c: char| match c.to_digit(36) {
+            Some(v) if v >= 1 && v as usize <= N => Ok(v as usize),
+            _ => Err(SudokuError::InvalidCharacter(c)),
+        };
+
+        // A lone value, as a given or after the + of a placed value.
+        let single = |token: &str| {
+            let mut chars = token.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => value(c),
+                (_, Some(c)) => Err(SudokuError::InvalidCharacter(c)),
+                (None, None) => Err(SudokuError::InvalidCharacter('+')),
+            }
+        };
+
+        // Start from the givens, so that they are marked as such, then fill in the rest.
+        let mut givens = [[0; N]; N];
+        let mut rest = Vec::new();
+        for (idx, token) in tokens.iter().enumerate() {
+            let (ridx, cidx) = (idx / N, idx % N);
+            if let Some(opts) = token.strip_prefix('[') {
+                let opts = opts
+                    .strip_suffix(']')
+                    .ok_or(SudokuError::InvalidCharacter('['))?;
+                let mut set = BitSet::new(&[]);
+                for c in opts.chars() {
+                    set = set.set(value(c)?);
+                }
+                rest.push((ridx, cidx, Cell::Options(set)));
+            } else if let Some(placed) = token.strip_prefix('+') {
+                rest.push((ridx, cidx, Cell::Value(single(placed)?)));
+            } else {
+                givens[ridx][cidx] = single(token)?;
+            }
+        }
+        let mut board = Board::new(givens)?;
+        for (ridx, cidx, cell) in rest {
+            board.put(ridx, cidx, cell);
+        }
+        board.check()?;
+        Ok(board)
+    }
+
+    fn set(&mut self, row: usize, col: usize, value: Cell) -> Result<(), SudokuError> {
+        self.put(row, col, value);
+        self.check()
+    }
+
+    // The contents of the given cell.  Fails if the cell is off the board.
+    pub fn get(&self, row: usize, col: usize) -> Result<Cell, SudokuError> {
+        Self::in_bounds(row, col)?;
+        Ok(self.cells[row][col])
+    }
+
+    fn in_bounds(row: usize, col: usize) -> Result<(), SudokuError> {
+        if row >= N || col >= N {
+            return Err(SudokuError::OutOfBounds { row, col });
+        }
+        Ok(())
+    }
+
+    // Place value in the given cell, as a player would, removing it from the options of the
+    // cell's peers so that their pencil marks stay consistent.  Fails if value is out of range,
+    // the cell is already solved, or value breaks the rules, in which case the board is left
+    // alone.
+    pub fn place(&mut self, row: usize, col: usize, value: usize) -> Result<(), SudokuError> {
+        Self::in_bounds(row, col)?;
+        if value == 0 || value > N {
+            return Err(SudokuError::InvalidValue { row, col, value });
+        }
+        let old = self.cells[row][col];
+        if let Cell::Value(_) = old {
+            return Err(SudokuError::AlreadySolved { row, col });
+        }
+        if !self.unplaced(row, col).has(value) {
+            return Err(SudokuError::Duplicate(value));
+        }
+        // The variant rules, e.g. cage sums, are only caught by check.
+        if let Err(err) = self.set(row, col, Cell::Value(value)) {
+            self.put(row, col, old);
+            return Err(err);
+        }
+        for ridx in 0..N {
+            for cidx in 0..N {
+                if self.is_peer(row, col, ridx, cidx) {
+                    self.eliminate(ridx, cidx, value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Whether place would accept value in the given cell: the cell is unsolved, and no peer
+    // already holds the value.  Cells off the board and values out of range can't be placed.
+    pub fn can_place(&self, row: usize, col: usize, value: usize) -> bool {
+        (1..=N).contains(&value)
+            && self
+                .candidates(row, col)
+                .is_ok_and(|candidates| candidates.has(value))
+    }
+
+    // Erase the given cell back to unknown, as an editor would, undoing place: the cell gets
+    // every candidate not placed among its peers, and the peers get the erased value back
+    // wherever it is again possible.  Givens can be cleared too, and stop being givens, so
+    // that an editor can redefine the puzzle.  Fails if the cell is off the board.
+    pub fn clear(&mut self, row: usize, col: usize) -> Result<(), SudokuError> {
+        let value = match self.get(row, col)? {
+            Cell::Value(value) => value,
+            Cell::Options(_) => return Ok(()),
+        };
+        self.givens[row][col] = false;
+        // Putting any options frees the value, so that unplaced no longer rules it out.
+        self.put(row, col, Cell::Options(BitSet::all(N)));
+        self.cells[row][col] = Cell::Options(self.unplaced(row, col));
+        for ridx in 0..N {
+            for cidx in 0..N {
+                if let Cell::Options(opts) = self.cells[ridx][cidx] {
+                    if self.is_peer(row, col, ridx, cidx) && self.unplaced(ridx, cidx).has(value) {
+                        self.cells[ridx][cidx] = Cell::Options(opts.set(value));
                     }
-                    // Anything else is an error.
-                    _ => return Err(format!("Found invalid input character: {}", c)),
                 }
             }
-            i += 1;
         }
-        return Board::new(raw_board);
+        Ok(())
+    }
+
+    // Whether the two cells are distinct but share a row, column, region, cage, or constrained
+    // diagonal, or are a constrained knight's move apart, and so can't hold the same value.
+    fn is_peer(&self, ridx: usize, cidx: usize, other_ridx: usize, other_cidx: usize) -> bool {
+        if (other_ridx, other_cidx) == (ridx, cidx) {
+            return false;
+        }
+        other_ridx == ridx
+            || other_cidx == cidx
+            || self.regions.of(other_ridx, other_cidx) == self.regions.of(ridx, cidx)
+            || (self.constraints.diagonals
+                && ((ridx == cidx && other_ridx == other_cidx)
+                    || (ridx + cidx == N - 1 && other_ridx + other_cidx == N - 1)))
+            || (self.cages.of(ridx, cidx).is_some()
+                && self.cages.of(ridx, cidx) == self.cages.of(other_ridx, other_cidx))
+            || (self.constraints.knight
+                && Self::knight_peers(ridx, cidx).any(|peer| peer == (other_ridx, other_cidx)))
     }
 
-    fn set(&mut self, row: usize, col: usize, value: Cell) -> Result<(), String> {
+    // Put value in the given cell without checking the board, keeping the masks of unplaced
+    // values up to date.
+    fn put(&mut self, row: usize, col: usize, value: Cell) {
+        let region = self.regions.of(row, col);
+        if let Cell::Value(v) = self.cells[row][col] {
+            self.row_unplaced[row] = self.row_unplaced[row].set(v);
+            self.col_unplaced[col] = self.col_unplaced[col].set(v);
+            self.region_unplaced[region] = self.region_unplaced[region].set(v);
+        }
+        if let Cell::Value(v) = value {
+            self.row_unplaced[row] = self.row_unplaced[row].unset(v);
+            self.col_unplaced[col] = self.col_unplaced[col].unset(v);
+            self.region_unplaced[region] = self.region_unplaced[region].unset(v);
+        }
         self.cells[row][col] = value;
-        self.check()
     }
 
-    fn row(&self, idx: usize) -> views::Row {
+    // Rebuild the masks of unplaced values from scratch, for when the cells or regions have
+    // been changed wholesale.
+    fn recompute_unplaced(&mut self) {
+        for idx in 0..N {
+            self.row_unplaced[idx] = views::mask::<N>(self.row(idx));
+            self.col_unplaced[idx] = views::mask::<N>(self.col(idx));
+            self.region_unplaced[idx] = views::mask::<N>(self.region(idx));
+        }
+    }
+
+    fn row(&self, idx: usize) -> views::Row<'_, N> {
         views::Row::new(self, idx)
     }
 
-    fn col(&self, idx: usize) -> views::Column {
+    fn col(&self, idx: usize) -> views::Column<'_, N> {
         views::Column::new(self, idx)
     }
 
-    fn subsquare(&self, ridx: usize, cidx: usize) -> views::SubSquare {
-        views::SubSquare::new(self, ridx, cidx)
+    fn region(&self, idx: usize) -> views::Region<'_, N> {
+        views::Region::new(self, idx)
     }
 
-    pub fn check(&self) -> Result<(), String> {
+    fn diagonal(&self, anti: bool) -> views::Diagonal<'_, N> {
+        views::Diagonal::new(self, anti)
+    }
+
+    pub fn check(&self) -> Result<(), SudokuError> {
         // Check each row
-        for idx in 0..9 {
-            views::check(self.row(idx))?;
+        for idx in 0..N {
+            self.check_row(idx)?;
         }
 
         // Check each column
-        for idx in 0..9 {
-            views::check(self.col(idx))?;
+        for idx in 0..N {
+            self.check_col(idx)?;
+        }
+
+        // Check each region
+        for idx in 0..N {
+            self.check_box(idx)?;
+        }
+
+        // Check the diagonals, if they are constrained.
+        if self.constraints.diagonals {
+            views::check(self.diagonal(false))?;
+            views::check(self.diagonal(true))?;
         }
 
-        // Check each subsquare
-        for idx in 0..9 {
-            views::check(self.subsquare(idx / 3, idx % 3))?;
+        // Check the knight's moves, if they are constrained.
+        if self.constraints.knight {
+            self.check_knight()?;
         }
 
+        // Check the cages of a Killer Sudoku.
+        self.check_cages()?;
+
+        // Check any set-equality constraints.
+        self.check_set_equals()?;
+
         // If everything checks out, then we are good!
         Ok(())
     }
 
-    fn solve_one(&mut self) -> Result<(u32, bool), String> {
-        let mut options = 0;
-        let mut changed = false;
+    // Check a single row, e.g. after editing one of its cells.
+    pub fn check_row(&self, idx: usize) -> Result<(), SudokuError> {
+        if idx >= N {
+            return Err(SudokuError::InvalidUnit(Unit::Row(idx)));
+        }
+        views::check(self.row(idx))
+    }
 
-        // First we check whether 0 square's available options consist of a single value.
-        for ridx in 0..9 {
-            for cidx in 0..9 {
-                if let Cell::Options(og_opts) = self.cells[ridx][cidx] {
-                    let opts = og_opts
-                        .intersect(views::mask(self.row(ridx)))
-                        .intersect(views::mask(self.col(cidx)))
-                        .intersect(views::mask(self.subsquare(ridx / 3, cidx / 3)));
-                    if opts.empty() {
-                        // If there are no options, then something went wrong.
-                        return Err(format!(
-                            "There are no remaining options for {}, {}",
-                            ridx, cidx
-                        ));
-                    } else if let Some(value) = opts.singleton() {
-                        // If it's a power of two, then there's only one option.
-                        self.set(ridx, cidx, Cell::Value(value))?;
-                        changed = true;
-                    } else {
-                        self.set(ridx, cidx, Cell::Options(opts))?;
-                        if og_opts != opts {
-                            changed = true;
+    // Check a single column.
+    pub fn check_col(&self, idx: usize) -> Result<(), SudokuError> {
+        if idx >= N {
+            return Err(SudokuError::InvalidUnit(Unit::Col(idx)));
+        }
+        views::check(self.col(idx))
+    }
+
+    // Check a single box (or region, on a Jigsaw board), numbered as for region.
+    pub fn check_box(&self, idx: usize) -> Result<(), SudokuError> {
+        if idx >= N {
+            return Err(SudokuError::InvalidUnit(Unit::Box(idx)));
+        }
+        views::check(self.region(idx))
+    }
+
+    // Whether the given row holds every value exactly once.  A row of N cells can only hold
+    // all N values by holding each once, so it is complete when none are missing.
+    pub fn is_row_complete(&self, idx: usize) -> bool {
+        idx < N && views::mask::<N>(self.row(idx)).empty()
+    }
+
+    // Whether the given column holds every value exactly once.
+    pub fn is_col_complete(&self, idx: usize) -> bool {
+        idx < N && views::mask::<N>(self.col(idx)).empty()
+    }
+
+    // Whether the given box (or region) holds every value exactly once.
+    pub fn is_box_complete(&self, idx: usize) -> bool {
+        idx < N && views::mask::<N>(self.region(idx)).empty()
+    }
+
+    // Whether the given cell held a value when the board was created (a clue), as opposed to
+    // being filled in later.
+    pub fn is_given(&self, row: usize, col: usize) -> bool {
+        // Cells off the board were never given.
+        row < N && col < N && self.givens[row][col]
+    }
+
+    // Clear every value that wasn't given when the board was created, restoring those cells
+    // to having all options.
+    pub fn reset(&mut self) {
+        let all = bitset::BitSet::all(N);
+        for (row, givens) in self.cells.iter_mut().zip(self.givens.iter()) {
+            for (cell, &given) in row.iter_mut().zip(givens.iter()) {
+                if !given {
+                    *cell = Cell::Options(all);
+                }
+            }
+        }
+        self.recompute_unplaced();
+    }
+
+    // Whether the two boards hold the same values in the same cells, ignoring the options of
+    // the cells that are unsolved on both.  Unlike ==, this doesn't care how far along the
+    // candidates of either board are.
+    pub fn same_values(&self, other: &Board<N>) -> bool {
+        self.cells
+            .iter()
+            .flatten()
+            .zip(other.cells.iter().flatten())
+            .all(|pair| match pair {
+                (Cell::Value(lhs), Cell::Value(rhs)) => lhs == rhs,
+                (Cell::Options(_), Cell::Options(_)) => true,
+                _ => false,
+            })
+    }
+
+    // Whether the two boards agree wherever both hold a value, with an unsolved cell on either
+    // matching anything.  Unlike same_values, a solved board agrees with any board part way to
+    // solving it, e.g. to check that a mid-solve board is on track.
+    pub fn values_eq(&self, other: &Board<N>) -> bool {
+        self.cells
+            .iter()
+            .flatten()
+            .zip(other.cells.iter().flatten())
+            .all(|pair| match pair {
+                (Cell::Value(lhs), Cell::Value(rhs)) => lhs == rhs,
+                _ => true,
+            })
+    }
+
+    // Every cell where the two boards differ, as (row, column, this cell, other cell), in
+    // row-major order.  Unlike same_values, differing options count, so compare solved boards
+    // (or ones filled to the same extent) to list just the mismatched values.
+    pub fn diff(&self, other: &Board<N>) -> Vec<(usize, usize, Cell, Cell)> {
+        let mut diffs = Vec::new();
+        for (ridx, (lhs, rhs)) in self.cells.iter().zip(other.cells.iter()).enumerate() {
+            for (cidx, (&lhs, &rhs)) in lhs.iter().zip(rhs.iter()).enumerate() {
+                if lhs != rhs {
+                    diffs.push((ridx, cidx, lhs, rhs));
+                }
+            }
+        }
+        diffs
+    }
+
+    // The number of options remaining across the board, counting those already ruled out by
+    // the values placed, with solved cells contributing none.  This only shrinks as the board
+    // is solved, so it makes a handy measure of progress.
+    pub fn total_candidates(&self) -> usize {
+        (0..N)
+            .flat_map(|ridx| (0..N).map(move |cidx| (ridx, cidx)))
+            .map(|(ridx, cidx)| self.options(ridx, cidx).count())
+            .sum()
+    }
+
+    // The number of cells on the board that hold a value.
+    pub fn count_filled(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .filter(|cell| matches!(cell, Cell::Value(_)))
+                    .count()
+            })
+            .sum()
+    }
+
+    // Whether every cell on the board holds a value.
+    pub fn is_complete(&self) -> bool {
+        self.cells
+            .iter()
+            .all(|row| row.iter().all(|cell| matches!(cell, Cell::Value(_))))
+    }
+
+    // Classify the board by how many of its cells are filled, and whether they follow the
+    // rules.
+    pub fn status(&self) -> BoardStatus {
+        if self.check().is_err() {
+            return BoardStatus::Invalid;
+        }
+        match self.count_filled() {
+            0 => BoardStatus::Empty,
+            filled if filled == N * N => BoardStatus::Complete,
+            _ => BoardStatus::Partial,
+        }
+    }
+
+    pub fn solve_one(&mut self) -> Result<(u32, bool), SudokuError> {
+        let (options, changed) = self.solve_one_diff()?;
+        Ok((options, !changed.is_empty()))
+    }
+
+    // Apply the default strategies as solve_one does, returning the number of cells that remain
+    // unsolved and the (row, column) of each cell whose value or options changed, e.g. to
+    // redraw just those.
+    pub fn solve_one_diff(&mut self) -> Result<(u32, Vec<(usize, usize)>), SudokuError> {
+        let before = self.cells;
+        let (options, _) = self.solve_one_with(Self::DEFAULT_STRATEGIES)?;
+        let changed = (0..N)
+            .flat_map(|ridx| (0..N).map(move |cidx| (ridx, cidx)))
+            .filter(|&(ridx, cidx)| before[ridx][cidx] != self.cells[ridx][cidx])
+            .collect();
+        Ok((options, changed))
+    }
+
+    // Apply the default strategies as solve_one does, returning the number of cells that remain
+    // unsolved and the number of cells solved by naked singles and by hidden singles, e.g. to
+    // see which technique did the work in this pass.
+    pub fn solve_one_counts(&mut self) -> Result<(u32, usize, usize), SudokuError> {
+        let (mut naked, mut hidden) = (0, 0);
+        let mut count = |m: Move| match m.technique {
+            Technique::NakedSingle => naked += 1,
+            Technique::HiddenSingle => hidden += 1,
+            _ => {}
+        };
+        let (options, _) = self.solve_one_observed(Self::DEFAULT_STRATEGIES, Some(&mut count))?;
+        Ok((options, naked, hidden))
+    }
+
+    // Apply each of the strategies in order, returning the number of cells that remain
+    // unsolved and whether any of the strategies changed the board.
+    pub fn solve_one_with(
+        &mut self,
+        strategies: &[&dyn Strategy<N>],
+    ) -> Result<(u32, bool), SudokuError> {
+        self.solve_one_observed(strategies, None)
+    }
+
+    // Apply the strategies as solve_one_with does, telling the observer (if any) about each
+    // value that they place.
+    fn solve_one_observed(
+        &mut self,
+        strategies: &[&dyn Strategy<N>],
+        mut observer: Option<&mut dyn FnMut(Move)>,
+    ) -> Result<(u32, bool), SudokuError> {
+        let mut changed = false;
+        for strategy in strategies {
+            let before = self.cells;
+            if strategy.apply(self)? {
+                changed = true;
+            }
+            if let Some(observe) = observer.as_mut() {
+                // Report the cells that this strategy solved.
+                for (ridx, (was, now)) in before.iter().zip(self.cells.iter()).enumerate() {
+                    for (cidx, pair) in was.iter().zip(now.iter()).enumerate() {
+                        if let (Cell::Options(_), &Cell::Value(value)) = pair {
+                            observe(Move {
+                                row: ridx,
+                                col: cidx,
+                                value,
+                                technique: strategy.technique(),
+                            });
                         }
-                        options += 1; // This cell remains an Options.
                     }
                 }
             }
         }
         self.check()?;
 
-        // Second we check whether 0 square is the only square in a range that could hold a given value.
-        for ridx in 0..9 {
-            for cidx in 0..9 {
+        let mut options = 0;
+        for row in self.cells.iter() {
+            for cell in row.iter() {
+                if let Cell::Options(_) = cell {
+                    options += 1;
+                }
+            }
+        }
+        Ok((options, changed))
+    }
+
+    // The options remaining for the given cell, narrowed by the values already placed in its
+    // row, column, and region (and diagonals and knight's moves, when constrained).  Solved
+    // cells have no options.
+    fn options(&self, ridx: usize, cidx: usize) -> bitset::BitSet {
+        match self.cells[ridx][cidx] {
+            Cell::Value(_) => bitset::BitSet::new(&[]),
+            Cell::Options(opts) => opts.intersect(self.unplaced(ridx, cidx)),
+        }
+    }
+
+    // The values that aren't already placed in the given cell's row, column, and region (and
+    // diagonals and knight's moves, when constrained).
+    fn unplaced(&self, ridx: usize, cidx: usize) -> bitset::BitSet {
+        let mut opts = self.row_unplaced[ridx]
+            .intersect(self.col_unplaced[cidx])
+            .intersect(self.region_unplaced[self.regions.of(ridx, cidx)]);
+        if self.constraints.diagonals {
+            if ridx == cidx {
+                opts = opts.intersect(views::mask::<N>(self.diagonal(false)));
+            }
+            if ridx + cidx == N - 1 {
+                opts = opts.intersect(views::mask::<N>(self.diagonal(true)));
+            }
+        }
+        if self.constraints.knight {
+            for (other_ridx, other_cidx) in Self::knight_peers(ridx, cidx) {
+                if let Cell::Value(v) = self.cells[other_ridx][other_cidx] {
+                    opts = opts.unset(v);
+                }
+            }
+        }
+        opts
+    }
+
+    // The values that could legally go in the given cell, which is empty for solved cells.
+    // Unlike the options held in the cell, these don't reflect any eliminations made by the
+    // strategies.  Fails if the cell is off the board.
+    pub fn candidates(&self, row: usize, col: usize) -> Result<BitSet, SudokuError> {
+        Ok(match self.get(row, col)? {
+            Cell::Value(_) => BitSet::new(&[]),
+            Cell::Options(_) => self.unplaced(row, col),
+        })
+    }
+
+    // The candidates of every cell at once, as candidates gives them for unsolved cells, e.g.
+    // for drawing pencil marks.  Solved cells get just their value.
+    pub fn all_candidates(&self) -> [[BitSet; N]; N] {
+        let mut all = [[BitSet::new(&[]); N]; N];
+        for (ridx, row) in all.iter_mut().enumerate() {
+            for (cidx, candidates) in row.iter_mut().enumerate() {
+                *candidates = match self.cells[ridx][cidx] {
+                    Cell::Value(v) => BitSet::new(&[v]),
+                    Cell::Options(_) => self.unplaced(ridx, cidx),
+                };
+            }
+        }
+        all
+    }
+
+    // Pencil in every candidate: set the options of each unsolved cell to exactly the values
+    // not already placed among its peers, without solving or guessing.
+    pub fn fill_candidates(&mut self) {
+        for ridx in 0..N {
+            for cidx in 0..N {
+                if let Cell::Options(_) = self.cells[ridx][cidx] {
+                    self.cells[ridx][cidx] = Cell::Options(self.unplaced(ridx, cidx));
+                }
+            }
+        }
+    }
+
+    // Narrow the options of each unsolved cell to the values not already placed among its
+    // peers, returning whether any were removed.  Unlike solve_one, this never places a value,
+    // even when a cell is left with a single option.  Fails if a cell is left with none.
+    pub fn propagate(&mut self) -> Result<bool, SudokuError> {
+        let mut changed = false;
+        for ridx in 0..N {
+            for cidx in 0..N {
                 if let Cell::Options(opts) = self.cells[ridx][cidx] {
-                    for value in opts.foreach() {
-                        if views::frequency(self.row(ridx), value) == 1
-                            || views::frequency(self.col(cidx), value) == 1
-                            || views::frequency(self.subsquare(ridx / 3, cidx / 3), value) == 1
-                        {
-                            self.set(ridx, cidx, Cell::Value(value))?;
-                            changed = true;
-                            options -= 1; // We counted this cell above, but it's now concrete so remove it.
-                            break;
-                        }
+                    let narrowed = self.options(ridx, cidx);
+                    if narrowed.empty() {
+                        return Err(SudokuError::NoOptions {
+                            row: ridx,
+                            col: cidx,
+                        });
+                    }
+                    if narrowed != opts {
+                        self.cells[ridx][cidx] = Cell::Options(narrowed);
+                        changed = true;
                     }
                 }
             }
         }
+        Ok(changed)
+    }
+
+    // Remove value from the options of the given cell, returning whether it was present.
+    fn eliminate(&mut self, ridx: usize, cidx: usize, value: usize) -> bool {
+        if let Cell::Options(opts) = self.cells[ridx][cidx] {
+            if opts.has(value) {
+                self.cells[ridx][cidx] = Cell::Options(opts.unset(value));
+                return true;
+            }
+        }
+        false
+    }
+
+    // Solve the board, refusing boards with fewer than MIN_CLUES values as underdetermined
+    // rather than searching through their many solutions.
+    pub fn solve(&mut self) -> Result<(), SudokuError> {
+        self.solve_with_min_clues(Self::MIN_CLUES)
+    }
+
+    // Solve the board as solve does, with the given minimum number of values (0 for none).
+    // Extra constraints, cages, and custom constraints pin down a board beyond its values, so
+    // boards with any of those aren't held to the minimum.
+    pub fn solve_with_min_clues(&mut self, min_clues: usize) -> Result<(), SudokuError> {
+        self.check_clues(min_clues)?;
+        self.solve_with(Self::DEFAULT_STRATEGIES)
+    }
+
+    // Solve the board as solve does, but give up with DidNotConverge once logic has taken the
+    // given number of passes over the board between guesses, rather than MAX_PASSES.
+    pub fn solve_with_max_passes(&mut self, max_passes: usize) -> Result<(), SudokuError> {
+        self.check_clues(Self::MIN_CLUES)?;
+        self.solve_observed(
+            Self::DEFAULT_STRATEGIES,
+            ValueOrder::Ascending,
+            max_passes,
+            None,
+        )
+        .map(|_| ())
+    }
+
+    // Solve the board as solve does, returning whether any guessing was needed, i.e. whether
+    // logic alone couldn't solve it.
+    pub fn solve_report(&mut self) -> Result<bool, SudokuError> {
+        self.check_clues(Self::MIN_CLUES)?;
+        let guesses = self.solve_with_order(Self::DEFAULT_STRATEGIES, ValueOrder::Ascending)?;
+        Ok(guesses > 0)
+    }
+
+    // Fail with Underdetermined if the board has fewer than the given number of values,
+    // unless it is a variant board.
+    fn check_clues(&self, min_clues: usize) -> Result<(), SudokuError> {
+        let filled = self.count_filled();
+        let variant = self.constraints != ExtraConstraints::default()
+            || !self.cages.is_empty()
+            || !self.sets.is_empty();
+        if filled < min_clues && !variant {
+            return Err(SudokuError::Underdetermined {
+                clues: filled,
+                wanted: min_clues,
+            });
+        }
+        Ok(())
+    }
+
+    // Solve as much of the board as logic allows, without ever guessing, leaving the cells it
+    // can't solve as options narrowed by the strategies.  This succeeds whether or not the
+    // board ends up solved, so check is_complete to tell.
+    pub fn solve_logical(&mut self) -> Result<(), SudokuError> {
+        self.solve_logical_with_max_passes(Self::MAX_PASSES)
+    }
+
+    // Solve as much of the board as logic allows, as solve_logical does, giving up with
+    // DidNotConverge after the given number of passes over the board.
+    pub fn solve_logical_with_max_passes(&mut self, max_passes: usize) -> Result<(), SudokuError> {
         self.check()?;
+        self.solve_logically_observed(Self::DEFAULT_STRATEGIES, max_passes, None)
+            .map(|_| ())
+    }
 
-        Ok((options, changed))
+    // Solve the board as solve does, then double check that the result is complete and follows
+    // the rules.  Failing that check is a solver bug, reported as InternalInconsistency.
+    pub fn solve_verified(&mut self) -> Result<(), SudokuError> {
+        self.solve()?;
+        if !self.is_complete() || self.check().is_err() {
+            return Err(SudokuError::InternalInconsistency);
+        }
+        Ok(())
     }
 
-    pub fn solve(&mut self) -> Result<(), String> {
-        for _ in 1..1000 {
-            match self.solve_one() {
-                Ok((options, changed)) => {
-                    // If there are no options left, then we have completely solved the puzzle!
-                    if options == 0 {
-                        return Ok(());
-                    }
+    // Solve the board as solve does, returning how long it took.
+    #[cfg(feature = "std")]
+    pub fn solve_timed(&mut self) -> Result<std::time::Duration, SudokuError> {
+        let start = std::time::Instant::now();
+        self.solve()?;
+        Ok(start.elapsed())
+    }
 
-                    // If the puzzle changed this iteration, then we're still making progress.  Keep going!
-                    if changed {
-                        continue;
-                    }
+    // Solve the board using the given strategies, applied in order on each pass, falling back
+    // on speculation when they stall.
+    pub fn solve_with(&mut self, strategies: &[&dyn Strategy<N>]) -> Result<(), SudokuError> {
+        self.solve_with_order(strategies, ValueOrder::Ascending)
+            .map(|_| ())
+    }
+
+    // Solve the board as solve_with does, trying the options of each guessed cell in the given
+    // order, and returning the number of guesses made.  Rather than recursing for each guess,
+    // we keep a stack of the guesses we have made, so deeply nested guesses can't overflow the
+    // call stack.
+    pub fn solve_with_order(
+        &mut self,
+        strategies: &[&dyn Strategy<N>],
+        order: ValueOrder,
+    ) -> Result<usize, SudokuError> {
+        self.solve_observed(strategies, order, Self::MAX_PASSES, None)
+    }
+
+    // Solve the board as solve_with_order does, telling the observer (if any) about each value
+    // placed, each guess, and each guess retracted.  On failure, the board is left as it was
+    // given rather than part way through.
+    fn solve_observed(
+        &mut self,
+        strategies: &[&dyn Strategy<N>],
+        order: ValueOrder,
+        max_passes: usize,
+        observer: Option<&mut dyn FnMut(Move)>,
+    ) -> Result<usize, SudokuError> {
+        let saved = self.snapshot();
+        let result = self.search(strategies, order, max_passes, observer);
+        if result.is_err() {
+            self.restore(&saved);
+        }
+        result
+    }
+
+    // Solve the board as solve_observed does, leaving it however far it got on failure.
+    fn search(
+        &mut self,
+        strategies: &[&dyn Strategy<N>],
+        order: ValueOrder,
+        max_passes: usize,
+        mut observer: Option<&mut dyn FnMut(Move)>,
+    ) -> Result<usize, SudokuError> {
+        // There's nothing to do for a board that breaks the rules, or is already solved.
+        self.check()?;
+        if self.is_complete() {
+            return Ok(0);
+        }
+
+        let mut count = 0;
+        // Each guess is a snapshot of the board when it stalled, the cell with the fewest
+        // options on it, the options for that cell which have yet to be tried, and the option
+        // currently being tried (if any).
+        let mut guesses: Vec<(Snapshot<N>, usize, usize, BitSet, Option<usize>)> = Vec::new();
+        loop {
+            match self.solve_logically_observed(
+                strategies,
+                max_passes,
+                observer.as_mut().map(|f| &mut **f as &mut dyn FnMut(Move)),
+            ) {
+                // If there are no options left, then we have completely solved the puzzle!
+                Ok(true) => return Ok(count),
+                // There are options left, but we have stalled.  We pick the cell with the
+                // fewest options as our speculation candidate, and try each of its options in
+                // turn until one succeeds.
+                Ok(false) => {
+                    let (candidate_rdx, candidate_cdx) = self.most_constrained();
+                    let opts = match self.cells[candidate_rdx][candidate_cdx] {
+                        Cell::Options(opts) => opts,
+                        Cell::Value(_) => BitSet::new(&[]),
+                    };
+                    guesses.push((self.snapshot(), candidate_rdx, candidate_cdx, opts, None));
+                }
+                // The solver gave up, so we can't say whether there's a solution.
+                Err(SudokuError::DidNotConverge) => return Err(SudokuError::DidNotConverge),
+                // The board we were given is contradictory, which logic alone can pin down.
+                Err(err) if guesses.is_empty() => return Err(self.explain(err)),
+                // The most recent guess led to failure.
+                Err(_) => {}
+            }
 
-                    // There are options left, but we have stalled.  Find one of the remaining
-                    // options and try to recursively solve a copy of the board for each option
-                    // until one succeeds.
-                    // We pick the cell with the fewest options as our speculation candidate.
-                    let (mut candidate_rdx, mut candidate_cdx, mut count) = (0, 0, 9);
-                    for ridx in 0..9 {
-                        for cidx in 0..9 {
-                            if let Cell::Options(opts) = self.cells[ridx][cidx] {
-                                if opts.count() <= count {
-                                    candidate_rdx = ridx;
-                                    candidate_cdx = cidx;
-                                    count = opts.count();
-                                }
+            // Move on to the next option of the most recent guess, backing up to earlier
+            // guesses as each runs out of options.
+            loop {
+                let (saved, candidate_rdx, candidate_cdx, opts, current) = match guesses.last_mut()
+                {
+                    Some(guess) => guess,
+                    None => return Err(SudokuError::Unsolvable),
+                };
+                let (candidate_rdx, candidate_cdx) = (*candidate_rdx, *candidate_cdx);
+                // Undo the previous attempt before choosing the next option to try.
+                self.restore(saved);
+                if let (Some(value), Some(observe)) = (current.take(), observer.as_mut()) {
+                    observe(Move {
+                        row: candidate_rdx,
+                        col: candidate_cdx,
+                        value,
+                        technique: Technique::Retract,
+                    });
+                }
+                match self.next_option(candidate_rdx, candidate_cdx, *opts, order) {
+                    Some(value) => {
+                        *opts = opts.unset(value);
+                        count += 1;
+                        // Speculate the value of this cell.  Strategies that don't narrow
+                        // options may leave values here that conflict with a peer, so we skip
+                        // those rather than failing outright.
+                        if self
+                            .set(candidate_rdx, candidate_cdx, Cell::Value(value))
+                            .is_ok()
+                        {
+                            *current = Some(value);
+                            if let Some(observe) = observer.as_mut() {
+                                observe(Move {
+                                    row: candidate_rdx,
+                                    col: candidate_cdx,
+                                    value,
+                                    technique: Technique::Guess,
+                                });
                             }
+                            break;
                         }
                     }
-                    if let Cell::Options(opts) = self.cells[candidate_rdx][candidate_cdx] {
-                        for value in opts.foreach() {
-                            // Create a copy of the board with which we will speculate the value of this cell.
-                            let mut speculator = *self;
-                            speculator.set(candidate_rdx, candidate_cdx, Cell::Value(value))?;
-                            // Try to recursively solve the board.
-                            if speculator.solve().is_ok() {
-                                self.cells = speculator.cells;
-                                return Ok(());
-                            }
-                        }
-                        return Err("All options lead to failure!".to_string());
+                    None => {
+                        // This leaves the board as it was when we first stalled.
+                        guesses.pop();
+                    }
+                }
+            }
+        }
+    }
+
+    // Save the parts of the board that change while solving.
+    fn snapshot(&self) -> Snapshot<N> {
+        Snapshot {
+            cells: self.cells,
+            row_unplaced: self.row_unplaced,
+            col_unplaced: self.col_unplaced,
+            region_unplaced: self.region_unplaced,
+        }
+    }
+
+    // Return the board to the state it was in when the snapshot was taken.
+    fn restore(&mut self, saved: &Snapshot<N>) {
+        self.cells = saved.cells;
+        self.row_unplaced = saved.row_unplaced;
+        self.col_unplaced = saved.col_unplaced;
+        self.region_unplaced = saved.region_unplaced;
+    }
+
+    // The (row, column, number of options) of every unsolved cell, fewest options first, as
+    // the solver picks cells to guess at.  Cells with the same number are in reading order.
+    pub fn blanks_by_constraint(&self) -> Vec<(usize, usize, usize)> {
+        let mut blanks = Vec::new();
+        for (ridx, row) in self.cells.iter().enumerate() {
+            for (cidx, cell) in row.iter().enumerate() {
+                if let Cell::Options(_) = cell {
+                    blanks.push((ridx, cidx, self.options(ridx, cidx).count()));
+                }
+            }
+        }
+        blanks.sort_by_key(|&(_, _, count)| count);
+        blanks
+    }
+
+    // Find the unsolved cell with the fewest options.
+    fn most_constrained(&self) -> (usize, usize) {
+        let (mut candidate_rdx, mut candidate_cdx, mut count) = (0, 0, N);
+        for ridx in 0..N {
+            for cidx in 0..N {
+                if let Cell::Options(opts) = self.cells[ridx][cidx] {
+                    if opts.count() <= count {
+                        candidate_rdx = ridx;
+                        candidate_cdx = cidx;
+                        count = opts.count();
                     }
                 }
-                Err(s) => {
-                    return Err(s);
+            }
+        }
+        (candidate_rdx, candidate_cdx)
+    }
+
+    // Apply the strategies until they stop making progress, returning whether they solved
+    // the board.
+    fn solve_logically(&mut self, strategies: &[&dyn Strategy<N>]) -> Result<bool, SudokuError> {
+        self.solve_logically_observed(strategies, Self::MAX_PASSES, None)
+    }
+
+    // Apply the strategies as solve_logically does, for at most max_passes passes, telling the
+    // observer (if any) about each value that they place.
+    fn solve_logically_observed(
+        &mut self,
+        strategies: &[&dyn Strategy<N>],
+        max_passes: usize,
+        mut observer: Option<&mut dyn FnMut(Move)>,
+    ) -> Result<bool, SudokuError> {
+        for _ in 0..max_passes {
+            let (options, changed) = self.solve_one_observed(
+                strategies,
+                observer.as_mut().map(|f| &mut **f as &mut dyn FnMut(Move)),
+            )?;
+            // If there are no options left, then we have completely solved the puzzle!
+            if options == 0 {
+                return Ok(true);
+            }
+            // If the puzzle didn't change this iteration, then we have stalled.
+            if !changed {
+                return Ok(false);
+            }
+        }
+        Err(SudokuError::DidNotConverge)
+    }
+
+    // Turn the error that showed the board to be contradictory into one that says where: the
+    // cell left without options, or the unit that ended up with a value twice.  Anything else
+    // is just Unsolvable.
+    fn explain(&self, err: SudokuError) -> SudokuError {
+        match err {
+            SudokuError::NoOptions { .. } => err,
+            SudokuError::Duplicate(value) => (0..N)
+                .map(Unit::Row)
+                .chain((0..N).map(Unit::Col))
+                .chain((0..N).map(Unit::Box))
+                .find(|&unit| views::check(self.unit_cells(unit).iter().copied()).is_err())
+                .map_or(SudokuError::Unsolvable, |unit| SudokuError::Conflict {
+                    value,
+                    unit,
+                }),
+            _ => SudokuError::Unsolvable,
+        }
+    }
+
+    // Whether the board has exactly one solution.
+    pub fn has_unique_solution(&self) -> bool {
+        self.count_solutions(2) == 1
+    }
+
+    // Whether the board is a proper puzzle: it breaks none of the rules, and has exactly one
+    // solution.  An empty board is valid, but not proper, since any solved board solves it.
+    pub fn is_proper(&self) -> bool {
+        self.check().is_ok() && self.has_unique_solution()
+    }
+
+    // Count the solutions to the board, stopping once limit have been found.
+    fn count_solutions(&self, limit: usize) -> usize {
+        let mut board = *self;
+        match board.solve_logically(Self::DEFAULT_STRATEGIES) {
+            Err(_) => return 0,   // The board is contradictory.
+            Ok(true) => return 1, // Logic alone solved the board.
+            Ok(false) => {}       // We need to speculate.
+        }
+
+        let (ridx, cidx) = board.most_constrained();
+        let mut count = 0;
+        if let Cell::Options(opts) = board.cells[ridx][cidx] {
+            for value in opts.foreach() {
+                let mut speculator = board;
+                if speculator.set(ridx, cidx, Cell::Value(value)).is_err() {
+                    continue;
+                }
+                count += speculator.count_solutions(limit - count);
+                if count >= limit {
+                    break;
                 }
             }
         }
-        Err("Solution did not close in 1000 iterations".to_string())
+        count
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests;
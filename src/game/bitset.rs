@@ -1,8 +1,10 @@
-use std::fmt;
+use core::fmt;
 
-#[derive(Copy, Clone, PartialEq)]
+use super::SudokuError;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct BitSet {
-    data: usize,
+    data: u32,
 }
 
 impl BitSet {
@@ -15,17 +17,79 @@ impl BitSet {
         bs
     }
 
-    pub fn foreach(&self) -> Biterator {
-        Biterator {
-            data: self.data,
-            index: 0,
+    // Build the set of digits on a standard board, as new does, but fail on any value outside
+    // 1 to 9 rather than setting a bit that can never be an option.
+    pub fn try_new(values: &[usize]) -> Result<BitSet, SudokuError> {
+        match values.iter().find(|&&v| v == 0 || v > 9) {
+            Some(&v) => Err(SudokuError::InvalidOption(v)),
+            None => Ok(BitSet::new(values)),
+        }
+    }
+
+    // The set of every digit on a standard board, 1 to 9.
+    pub fn full() -> BitSet {
+        BitSet::all(9)
+    }
+
+    // The set of values 1 to n.
+    pub fn all(n: usize) -> BitSet {
+        BitSet {
+            data: ((1 << n) - 1) << 1,
+        }
+    }
+
+    // The raw encoding of the set, with bit v set when value v is in it.  Value 0 is never in
+    // a set of options, so bit 0 is always clear.
+    pub fn bits(&self) -> u32 {
+        self.data
+    }
+
+    // The set encoded by raw, as bits returns it, keeping only the digits of a standard board,
+    // 1 to 9.  Bit 0 and the bits above 9 are ignored.
+    pub fn from_bits(raw: u32) -> BitSet {
+        BitSet::from_bits_upto(raw, 9)
+    }
+
+    // The set encoded by raw, as from_bits does, keeping only the values 1 to n, e.g. for a
+    // 16x16 board.
+    pub fn from_bits_upto(raw: u32, n: usize) -> BitSet {
+        BitSet {
+            data: raw & BitSet::all(n).data,
         }
     }
 
+    pub fn foreach(&self) -> Biterator {
+        Biterator { data: self.data }
+    }
+
+    // Each unordered pair of values in the set once, smaller value first, e.g. for finding
+    // pairs of candidates.
+    pub fn pairs(&self) -> impl Iterator<Item = (usize, usize)> {
+        let data = self.data;
+        self.foreach().flat_map(move |first| {
+            // Pair the value with each larger one.
+            let larger = Biterator {
+                data: data & !(u32::MAX >> (31 - first)),
+            };
+            larger.map(move |second| (first, second))
+        })
+    }
+
     pub fn empty(&self) -> bool {
         self.data == 0
     }
 
+    // Whether the set holds exactly the digits 1 to 9, as full does, e.g. a cell on a standard
+    // board that still has every option.
+    pub fn is_full(&self) -> bool {
+        self.is_full_upto(9)
+    }
+
+    // Whether the set holds exactly the values 1 to n, as is_full does, e.g. for a 16x16 board.
+    pub fn is_full_upto(&self, n: usize) -> bool {
+        *self == BitSet::all(n)
+    }
+
     pub fn count(&self) -> usize {
         self.data.count_ones() as usize
     }
@@ -37,7 +101,7 @@ impl BitSet {
         if self.data & (self.data - 1) != 0 {
             return None;
         }
-        return Some(self.data.trailing_zeros() as usize);
+        Some(self.data.trailing_zeros() as usize)
     }
 
     pub fn has(&self, value: usize) -> bool {
@@ -68,28 +132,39 @@ impl BitSet {
             data: self.data | other.data,
         }
     }
+
+    // The values in every one of the sets, starting from the first so that values past 9 on
+    // larger boards are kept.  With no sets, that is every digit of a standard board, as full
+    // gives.
+    pub fn intersect_all(sets: impl IntoIterator<Item = BitSet>) -> BitSet {
+        let mut sets = sets.into_iter();
+        match sets.next() {
+            Some(first) => sets.fold(first, |all, set| all.intersect(set)),
+            None => BitSet::full(),
+        }
+    }
+
+    // The values in any of the sets, which is none when there are no sets.
+    pub fn union_all(sets: impl IntoIterator<Item = BitSet>) -> BitSet {
+        sets.into_iter()
+            .fold(BitSet::new(&[]), |any, set| any.union(set))
+    }
 }
 
 pub struct Biterator {
-    data: usize,
-    index: usize,
+    data: u32,
 }
 impl Iterator for Biterator {
     type Item = usize;
 
     fn next(&mut self) -> Option<usize> {
-        let mut value = None;
-        while self.index != 16 {
-            if self.data & 1 != 0 {
-                value = Some(self.index);
-            }
-            self.data >>= 1;
-            self.index += 1;
-            if value != None {
-                break;
-            }
+        if self.data == 0 {
+            return None;
         }
-        value
+        // Yield the lowest set bit, and then clear it.
+        let value = self.data.trailing_zeros() as usize;
+        self.data &= self.data - 1;
+        Some(value)
     }
 }
 
@@ -105,5 +180,5 @@ impl fmt::Debug for BitSet {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests;
@@ -1,3 +1,6 @@
+// The original tests predate clippy's stricter lints, and are kept as they were written.
+#![allow(clippy::bool_assert_comparison)]
+
 use super::*;
 
 #[test]
@@ -106,6 +109,49 @@ fn test_iterating_alternating() {
     assert_eq!(idx, 15);
 }
 
+#[test]
+fn test_pairs() {
+    let set = BitSet::new(&[1, 2, 3]);
+    assert_eq!(set.pairs().count(), 3);
+    assert_eq!(
+        set.pairs().collect::<Vec<_>>(),
+        vec![(1, 2), (1, 3), (2, 3)]
+    );
+
+    assert_eq!(BitSet::new(&[]).pairs().count(), 0);
+    assert_eq!(BitSet::new(&[5]).pairs().count(), 0);
+    assert_eq!(BitSet::all(9).pairs().count(), 36);
+    // Values at the top of the range pair up too.
+    assert_eq!(
+        BitSet::new(&[0, 31]).pairs().collect::<Vec<_>>(),
+        vec![(0, 31)]
+    );
+}
+
+#[test]
+fn test_bits() {
+    for set in [
+        BitSet::new(&[]),
+        BitSet::new(&[1]),
+        BitSet::new(&[2, 5, 9]),
+        BitSet::all(9),
+    ] {
+        assert_eq!(BitSet::from_bits(set.bits()), set);
+    }
+    assert_eq!(BitSet::new(&[1, 3]).bits(), 0b1010);
+    assert_eq!(BitSet::all(9).bits(), 0b11_1111_1110);
+    // Zero is never a value.
+    assert_eq!(BitSet::from_bits(0b1011), BitSet::new(&[1, 3]));
+
+    // Nor is anything past 9, unless the board is larger.
+    assert_eq!(BitSet::from_bits(u32::MAX), BitSet::full());
+    assert_eq!(BitSet::from_bits(BitSet::all(16).bits()), BitSet::full());
+    let set = BitSet::new(&[2, 11, 16]);
+    assert_eq!(BitSet::from_bits_upto(set.bits(), 16), set);
+    assert_eq!(BitSet::from_bits_upto(u32::MAX, 16), BitSet::all(16));
+    assert_eq!(BitSet::from_bits_upto(u32::MAX, 4), BitSet::all(4));
+}
+
 #[test]
 fn test_formatting() {
     assert_eq!("{}", format!("{:?}", BitSet { data: 0b0 }));
@@ -116,3 +162,96 @@ fn test_formatting() {
         format!("{:?}", BitSet { data: 0b1111111110 })
     );
 }
+
+#[test]
+fn test_try_new() {
+    assert_eq!(BitSet::try_new(&[1, 5, 9]), Ok(BitSet::new(&[1, 5, 9])));
+    assert_eq!(BitSet::try_new(&[]), Ok(BitSet::new(&[])));
+    assert_eq!(
+        BitSet::try_new(&[0, 10, 99]),
+        Err(SudokuError::InvalidOption(0))
+    );
+    assert_eq!(
+        BitSet::try_new(&[3, 10]),
+        Err(SudokuError::InvalidOption(10))
+    );
+    assert_eq!(BitSet::full(), BitSet::all(9));
+}
+
+#[test]
+fn test_all() {
+    assert_eq!(BitSet::all(4), BitSet::new(&[1, 2, 3, 4]));
+    assert_eq!(BitSet::all(9), BitSet::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9]));
+    // 16x16 boards hold values up to 16.
+    assert_eq!(BitSet::all(16).foreach().last(), Some(16));
+    assert_eq!(BitSet::all(16).count(), 16);
+}
+
+#[test]
+fn test_size() {
+    // 32 bits is enough for the values of a 16x16 board, and keeps every Cell (and so every
+    // Board) smaller than a full word per set.
+    assert_eq!(std::mem::size_of::<BitSet>(), 4);
+}
+
+#[test]
+fn test_intersect_all() {
+    assert_eq!(BitSet::intersect_all(vec![]), BitSet::full());
+    let single = BitSet::new(&[2, 4, 6]);
+    assert_eq!(BitSet::intersect_all(vec![single]), single);
+    let sets = vec![
+        BitSet::new(&[1, 2, 3, 4]),
+        BitSet::new(&[2, 3, 4, 5]),
+        BitSet::new(&[3, 4, 5, 6]),
+    ];
+    assert_eq!(BitSet::intersect_all(sets), BitSet::new(&[3, 4]));
+
+    // Values past 9 survive on larger boards.
+    let sets = vec![BitSet::all(16), BitSet::new(&[2, 12, 16])];
+    assert_eq!(BitSet::intersect_all(sets), BitSet::new(&[2, 12, 16]));
+}
+
+#[test]
+fn test_union_all() {
+    assert_eq!(BitSet::union_all(vec![]), BitSet::new(&[]));
+    let single = BitSet::new(&[2, 4, 6]);
+    assert_eq!(BitSet::union_all(vec![single]), single);
+    let sets = vec![
+        BitSet::new(&[1]),
+        BitSet::new(&[3, 9]),
+        BitSet::new(&[1, 5]),
+    ];
+    assert_eq!(BitSet::union_all(sets), BitSet::new(&[1, 3, 5, 9]));
+}
+
+#[test]
+fn test_is_full() {
+    assert!(BitSet::full().is_full());
+    assert!(!BitSet::new(&[]).is_full());
+    // Only the digits of a standard board count, so a set for a larger one isn't full.
+    assert!(!BitSet::all(16).is_full());
+    assert!(!BitSet::all(4).is_full());
+
+    // A cell on an empty board has every option, until one is ruled out.
+    let board = super::super::Board::<9>::new([[0; 9]; 9]).expect("building board literal");
+    let opts = board.candidates(4, 4).expect("cell on the board");
+    assert!(opts.is_full());
+    assert!(!opts.unset(5).is_full());
+}
+
+#[test]
+fn test_is_full_upto() {
+    assert!(BitSet::all(4).is_full_upto(4));
+    assert!(BitSet::all(16).is_full_upto(16));
+    assert!(BitSet::full().is_full_upto(9));
+    // Values beyond n, or missing below it, both spoil it.
+    assert!(!BitSet::all(16).is_full_upto(9));
+    assert!(!BitSet::all(4).is_full_upto(16));
+    assert!(!BitSet::all(4).unset(2).is_full_upto(4));
+
+    // A cell on an empty 4x4 board has every option.
+    let board = super::super::Board::<4>::new([[0; 4]; 4]).expect("building board literal");
+    let opts = board.candidates(1, 2).expect("cell on the board");
+    assert!(opts.is_full_upto(4));
+    assert!(!opts.is_full());
+}
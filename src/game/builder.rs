@@ -0,0 +1,69 @@
+use super::{Board, SudokuError};
+
+// Assembles the values of a board piece by piece, e.g. from several sources, checking nothing
+// until build.  If any step fails, build reports the first failure.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BoardBuilder<const N: usize = 9> {
+    values: [[usize; N]; N],
+    error: Option<SudokuError>, // The first step to fail, if any.
+}
+
+impl<const N: usize> BoardBuilder<N> {
+    // Start from an empty board.
+    pub fn new() -> BoardBuilder<N> {
+        BoardBuilder {
+            values: [[0; N]; N],
+            error: None,
+        }
+    }
+
+    // Set the value of a single cell, where 0 blanks it.
+    pub fn set(mut self, row: usize, col: usize, value: usize) -> BoardBuilder<N> {
+        if row >= N || col >= N {
+            self.fail(SudokuError::OutOfBounds { row, col });
+        } else {
+            self.values[row][col] = value;
+        }
+        self
+    }
+
+    // Set the values of a whole row.
+    pub fn row(mut self, idx: usize, values: [usize; N]) -> BoardBuilder<N> {
+        if idx >= N {
+            self.fail(SudokuError::OutOfBounds { row: idx, col: 0 });
+        } else {
+            self.values[idx] = values;
+        }
+        self
+    }
+
+    // Set the value of every cell from a single line, as Board::parse_line reads it.
+    pub fn parse_line(mut self, line: &str) -> BoardBuilder<N> {
+        match Board::<N>::line_values(line) {
+            Ok(values) => self.values = values,
+            Err(err) => self.fail(err),
+        }
+        self
+    }
+
+    // Build the board, checking the values as Board::new does.
+    pub fn build(&self) -> Result<Board<N>, SudokuError> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Board::new(self.values),
+        }
+    }
+
+    fn fail(&mut self, err: SudokuError) {
+        self.error.get_or_insert(err);
+    }
+}
+
+impl<const N: usize> Default for BoardBuilder<N> {
+    fn default() -> BoardBuilder<N> {
+        BoardBuilder::new()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
@@ -0,0 +1,66 @@
+use super::*;
+
+const PUZZLE: &str =
+    "530070000600195000098000060800060003400803001700020006060000280000419005000080079";
+
+#[test]
+fn check_build() {
+    let want = Board::<9>::parse_line(PUZZLE).expect("building board line");
+
+    // Start from a line with the top row blanked, then fill it back in piece by piece.
+    let blanked = format!("{}{}", "0".repeat(9), &PUZZLE[9..]);
+    let board = BoardBuilder::new()
+        .parse_line(&blanked)
+        .row(0, [5, 3, 0, 0, 0, 0, 0, 0, 0])
+        .set(0, 4, 7)
+        .build()
+        .expect("building board");
+    assert_eq!(board, want);
+    assert!(board.is_given(0, 4));
+
+    // An empty builder builds an empty board.
+    let empty = BoardBuilder::<9>::default()
+        .build()
+        .expect("building board");
+    assert_eq!(
+        empty,
+        Board::new([[0; 9]; 9]).expect("building board literal")
+    );
+}
+
+#[test]
+fn check_build_errors() {
+    // Nothing is checked until build.
+    let builder = BoardBuilder::<9>::new().set(0, 0, 5).set(0, 1, 5);
+    assert_eq!(
+        builder.build(),
+        Err(SudokuError::Clash {
+            row: 0,
+            col: 1,
+            value: 5,
+            peer_row: 0,
+            peer_col: 0
+        })
+    );
+    assert_eq!(
+        builder.set(0, 1, 3).build().map(|b| b.count_filled()),
+        Ok(2)
+    );
+
+    // The first failing step is the one reported.
+    let builder = BoardBuilder::<9>::new()
+        .set(9, 0, 1)
+        .parse_line("123")
+        .row(0, [1; 9]);
+    assert_eq!(
+        builder.build(),
+        Err(SudokuError::OutOfBounds { row: 9, col: 0 })
+    );
+    assert_eq!(
+        BoardBuilder::<9>::new().parse_line("123").build(),
+        Err(SudokuError::LineLength {
+            length: 3,
+            wanted: 81
+        })
+    );
+}
@@ -0,0 +1,188 @@
+use alloc::vec::Vec;
+
+use super::bitset::BitSet;
+use super::strategy::{Strategy, Technique};
+use super::{Board, Cell, SudokuError};
+
+// A cage of Killer Sudoku: a group of cells whose values must all differ and add up to sum.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cage {
+    pub cells: Vec<(usize, usize)>,
+    pub sum: usize,
+}
+
+// The cages attached to a board.  These are kept as the cage of each cell, rather than as a
+// list of cells for each cage, so that Boards stay Copy.
+#[derive(Copy, Clone, PartialEq)]
+pub(super) struct Cages<const N: usize> {
+    of: [[u16; N]; N], // One more than the index of the cage holding each cell, or 0 if none.
+    sums: [[u16; N]; N], // The sum of the cage holding each cell.
+    count: usize,      // The number of cages.
+}
+
+impl<const N: usize> Cages<N> {
+    pub(super) fn none() -> Cages<N> {
+        Cages {
+            of: [[0; N]; N],
+            sums: [[0; N]; N],
+            count: 0,
+        }
+    }
+
+    // Whether there are no cages.
+    pub(super) fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    // The cage holding the given cell, if any.
+    pub(super) fn of(&self, ridx: usize, cidx: usize) -> Option<usize> {
+        match self.of[ridx][cidx] {
+            0 => None,
+            cage => Some(cage as usize - 1),
+        }
+    }
+
+    // The cells of the given cage, left to right and top to bottom.
+    fn cells(&self, cage: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..N)
+            .flat_map(|ridx| (0..N).map(move |cidx| (ridx, cidx)))
+            .filter(move |&(ridx, cidx)| self.of(ridx, cidx) == Some(cage))
+    }
+
+    // The sum of the given cage.
+    fn sum(&self, cage: usize) -> usize {
+        self.cells(cage)
+            .next()
+            .map_or(0, |(ridx, cidx)| self.sums[ridx][cidx] as usize)
+    }
+
+    // The cages of a board whose cell at (row, column) comes from source(row, column).
+    pub(super) fn moved(&self, source: impl Fn(usize, usize) -> (usize, usize)) -> Cages<N> {
+        let mut cages = *self;
+        for ridx in 0..N {
+            for cidx in 0..N {
+                let (src_ridx, src_cidx) = source(ridx, cidx);
+                cages.of[ridx][cidx] = self.of[src_ridx][src_cidx];
+                cages.sums[ridx][cidx] = self.sums[src_ridx][src_cidx];
+            }
+        }
+        cages
+    }
+}
+
+impl<const N: usize> Board<N> {
+    // Attach the cages of a Killer Sudoku to the board, checking that it still satisfies them.
+    // Each cage must hold at least one cell, and no cell may be in more than one cage.
+    pub fn with_cages(mut self, cages: Vec<Cage>) -> Result<Board<N>, SudokuError> {
+        let mut attached = Cages::none();
+        for (idx, cage) in cages.iter().enumerate() {
+            if cage.cells.is_empty() || cage.sum > u16::MAX as usize {
+                return Err(SudokuError::InvalidCage(idx));
+            }
+            for &(ridx, cidx) in cage.cells.iter() {
+                if ridx >= N || cidx >= N || attached.of[ridx][cidx] != 0 {
+                    return Err(SudokuError::InvalidCage(idx));
+                }
+                attached.of[ridx][cidx] = idx as u16 + 1;
+                attached.sums[ridx][cidx] = cage.sum as u16;
+            }
+        }
+        attached.count = cages.len();
+        self.cages = attached;
+        self.check()?;
+        Ok(self)
+    }
+
+    // Check that the values in each cage differ, don't exceed its sum, and once the cage is
+    // full, add up to it.
+    pub(super) fn check_cages(&self) -> Result<(), SudokuError> {
+        for cage in 0..self.cages.count {
+            let (mut seen, mut sum, mut full) = (BitSet::new(&[]), 0, true);
+            for (ridx, cidx) in self.cages.cells(cage) {
+                match self.cells[ridx][cidx] {
+                    Cell::Value(v) if seen.has(v) => return Err(SudokuError::Duplicate(v)),
+                    Cell::Value(v) => {
+                        seen = seen.set(v);
+                        sum += v;
+                    }
+                    Cell::Options(_) => full = false,
+                }
+            }
+            let wanted = self.cages.sum(cage);
+            if sum > wanted || (full && sum != wanted) {
+                return Err(SudokuError::CageSum { cage, sum, wanted });
+            }
+        }
+        Ok(())
+    }
+}
+
+// Whether the cells with the given options can take distinct values, none of them in used,
+// that add up to remaining.
+fn feasible(options: &[BitSet], used: BitSet, remaining: usize) -> bool {
+    match options.split_first() {
+        None => remaining == 0,
+        Some((first, rest)) => first.foreach().any(|value| {
+            !used.has(value)
+                && value <= remaining
+                && feasible(rest, used.set(value), remaining - value)
+        }),
+    }
+}
+
+// CageSums removes the options of each caged cell that can't be part of any way of filling
+// its cage with distinct values that add up to the cage's sum.  Boards without cages are left
+// alone.
+pub struct CageSums;
+
+impl<const N: usize> Strategy<N> for CageSums {
+    fn apply(&self, board: &mut Board<N>) -> Result<bool, SudokuError> {
+        let mut changed = false;
+        for cage in 0..board.cages.count {
+            let (mut used, mut remaining) = (BitSet::new(&[]), board.cages.sum(cage));
+            let mut open = Vec::new();
+            for (ridx, cidx) in board.cages.cells(cage) {
+                match board.cells[ridx][cidx] {
+                    Cell::Value(v) => {
+                        used = used.set(v);
+                        remaining = remaining.saturating_sub(v);
+                    }
+                    Cell::Options(_) => open.push((ridx, cidx)),
+                }
+            }
+
+            let mut options: Vec<BitSet> = open
+                .iter()
+                .map(|&(ridx, cidx)| board.options(ridx, cidx))
+                .collect();
+            for (idx, &(ridx, cidx)) in open.iter().enumerate() {
+                // Try each option of this cell against the options of the rest.
+                let mut others = options.clone();
+                others.remove(idx);
+                for value in options[idx].foreach() {
+                    let fits = !used.has(value)
+                        && value <= remaining
+                        && feasible(&others, used.set(value), remaining - value);
+                    if !fits && board.eliminate(ridx, cidx, value) {
+                        changed = true;
+                    }
+                }
+                options[idx] = board.options(ridx, cidx);
+                if options[idx].empty() {
+                    return Err(SudokuError::NoOptions {
+                        row: ridx,
+                        col: cidx,
+                    });
+                }
+            }
+        }
+        Ok(changed)
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::CageSum
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
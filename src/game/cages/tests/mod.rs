@@ -0,0 +1,130 @@
+use super::*;
+
+const SOLUTION: [[usize; 4]; 4] = [[1, 2, 3, 4], [3, 4, 1, 2], [2, 1, 4, 3], [4, 3, 2, 1]];
+
+// Two cages: the 1 and 3 down the left edge, and the 4, 3, and 1 in the bottom right corner.
+fn cages() -> Vec<Cage> {
+    vec![
+        Cage {
+            cells: vec![(0, 0), (1, 0)],
+            sum: 4,
+        },
+        Cage {
+            cells: vec![(2, 2), (2, 3), (3, 3)],
+            sum: 8,
+        },
+    ]
+}
+
+#[test]
+fn check_solve_killer() {
+    let puzzle =
+        Board::new([[1, 0, 0, 4], [0; 4], [0, 1, 0, 0], [0; 4]]).expect("building board literal");
+    // On its own, the puzzle has many solutions.
+    assert!(!puzzle.has_unique_solution());
+
+    // But the cages pin it down.
+    let mut input = puzzle.with_cages(cages()).expect("attaching cages");
+    assert!(input.has_unique_solution());
+
+    input.solve().expect("error finding solution");
+    assert_eq!(input, Board::new(SOLUTION).expect("building board literal"));
+}
+
+#[test]
+fn check_cage_sums() {
+    let board = Board::new(SOLUTION).expect("building board literal");
+    board.with_cages(cages()).expect("attaching cages");
+
+    // A full cage must add up to its sum.
+    let mut wrong = cages();
+    wrong[1].sum = 9;
+    assert_eq!(
+        board.with_cages(wrong).err(),
+        Some(SudokuError::CageSum {
+            cage: 1,
+            sum: 8,
+            wanted: 9
+        })
+    );
+
+    // A partial one can't go over it.
+    let partial =
+        Board::new([[1, 0, 0, 4], [3, 0, 0, 0], [0; 4], [0; 4]]).expect("building board literal");
+    let mut wrong = cages();
+    wrong[0].sum = 3;
+    assert_eq!(
+        partial.with_cages(wrong).err(),
+        Some(SudokuError::CageSum {
+            cage: 0,
+            sum: 4,
+            wanted: 3
+        })
+    );
+
+    // Values can't repeat within a cage, even when they aren't otherwise peers.
+    let board =
+        Board::new([[1, 0, 0, 0], [0; 4], [0; 4], [0, 0, 0, 1]]).expect("building board literal");
+    let diagonal = vec![Cage {
+        cells: vec![(0, 0), (3, 3)],
+        sum: 3,
+    }];
+    assert_eq!(
+        board.with_cages(diagonal).err(),
+        Some(SudokuError::Duplicate(1))
+    );
+}
+
+#[test]
+fn check_invalid_cages() {
+    let board = Board::<4>::new([[0; 4]; 4]).expect("building board literal");
+
+    let mut empty = cages();
+    empty[1].cells.clear();
+    assert_eq!(
+        board.with_cages(empty).err(),
+        Some(SudokuError::InvalidCage(1))
+    );
+
+    let mut off_board = cages();
+    off_board[0].cells.push((4, 0));
+    assert_eq!(
+        board.with_cages(off_board).err(),
+        Some(SudokuError::InvalidCage(0))
+    );
+
+    let mut overlapping = cages();
+    overlapping[1].cells.push((1, 0));
+    assert_eq!(
+        board.with_cages(overlapping).err(),
+        Some(SudokuError::InvalidCage(1))
+    );
+}
+
+#[test]
+fn test_cage_sums() {
+    // Two cells adding up to 3 must hold 1 and 2, and three adding up to 23 must hold 6, 8,
+    // and 9.
+    let mut board = Board::<9>::new([[0; 9]; 9])
+        .expect("building board literal")
+        .with_cages(vec![
+            Cage {
+                cells: vec![(0, 0), (0, 1)],
+                sum: 3,
+            },
+            Cage {
+                cells: vec![(4, 4), (5, 4), (5, 5)],
+                sum: 23,
+            },
+        ])
+        .expect("attaching cages");
+    assert_eq!(CageSums.apply(&mut board), Ok(true));
+    assert_eq!(board.cells[0][0], Cell::Options(BitSet::new(&[1, 2])));
+    assert_eq!(board.cells[0][1], Cell::Options(BitSet::new(&[1, 2])));
+    assert_eq!(board.cells[5][5], Cell::Options(BitSet::new(&[6, 8, 9])));
+    // Cells outside of the cages are left alone.
+    assert_eq!(board.cells[0][2], Cell::Options(BitSet::all(9)));
+
+    // Once it's done, there's nothing more to remove.
+    assert_eq!(CageSums.apply(&mut board), Ok(false));
+}
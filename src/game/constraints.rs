@@ -0,0 +1,168 @@
+use alloc::vec::Vec;
+
+use super::{Board, Cell, SudokuError};
+
+// Rules that variants of Sudoku add on top of the standard ones.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ExtraConstraints {
+    pub diagonals: bool, // Each of the two main diagonals must also hold 1-9 (X-Sudoku).
+    pub knight: bool,    // Cells a chess knight's move apart can't hold the same value.
+}
+
+// A custom rule relating arbitrary cells, for variants beyond those ExtraConstraints covers.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Constraint {
+    // The two sets of cells must hold the same values, counting repeats, once both are full
+    // (e.g. the Phistomefel ring and the corners around it).
+    SetEqual {
+        a: Vec<(usize, usize)>,
+        b: Vec<(usize, usize)>,
+    },
+}
+
+// The set-equality constraints attached to a board.  As with cages, these are kept as the set
+// holding each cell so that Boards stay Copy, which means no cell can be in more than one set.
+#[derive(Copy, Clone, PartialEq)]
+pub(super) struct SetEquals<const N: usize> {
+    of: [[u16; N]; N], // One more than twice the index of the constraint, plus 1 for its b side.
+    count: usize,      // The number of constraints.
+}
+
+impl<const N: usize> SetEquals<N> {
+    pub(super) fn none() -> SetEquals<N> {
+        SetEquals {
+            of: [[0; N]; N],
+            count: 0,
+        }
+    }
+
+    // Whether there are no set-equality constraints.
+    pub(super) fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    // The set-equality constraints of a board whose cell at (row, column) comes from
+    // source(row, column).
+    pub(super) fn moved(&self, source: impl Fn(usize, usize) -> (usize, usize)) -> SetEquals<N> {
+        let mut sets = *self;
+        for ridx in 0..N {
+            for cidx in 0..N {
+                let (src_ridx, src_cidx) = source(ridx, cidx);
+                sets.of[ridx][cidx] = self.of[src_ridx][src_cidx];
+            }
+        }
+        sets
+    }
+}
+
+// The offsets of the cells a knight's move away.
+const KNIGHT_MOVES: [(isize, isize); 8] = [
+    (-2, -1),
+    (-2, 1),
+    (-1, -2),
+    (-1, 2),
+    (1, -2),
+    (1, 2),
+    (2, -1),
+    (2, 1),
+];
+
+impl<const N: usize> Board<N> {
+    // Apply the given extra constraints to the board, checking that it still satisfies them.
+    pub fn with_constraints(
+        mut self,
+        constraints: ExtraConstraints,
+    ) -> Result<Board<N>, SudokuError> {
+        self.constraints = constraints;
+        self.check()?;
+        Ok(self)
+    }
+
+    // Attach custom constraints to the board, checking that it still satisfies them.  The two
+    // sets of a SetEqual must be the same size, and no cell may be in more than one set.
+    pub fn with_custom_constraints(
+        mut self,
+        constraints: Vec<Constraint>,
+    ) -> Result<Board<N>, SudokuError> {
+        let mut sets = SetEquals::none();
+        for (idx, constraint) in constraints.iter().enumerate() {
+            match constraint {
+                Constraint::SetEqual { a, b } => {
+                    if a.is_empty() || a.len() != b.len() {
+                        return Err(SudokuError::InvalidConstraint(idx));
+                    }
+                    for (side, cells) in [a, b].iter().enumerate() {
+                        for &(ridx, cidx) in cells.iter() {
+                            if ridx >= N || cidx >= N || sets.of[ridx][cidx] != 0 {
+                                return Err(SudokuError::InvalidConstraint(idx));
+                            }
+                            sets.of[ridx][cidx] = (2 * idx + side) as u16 + 1;
+                        }
+                    }
+                }
+            }
+        }
+        sets.count = constraints.len();
+        self.sets = sets;
+        self.check()?;
+        Ok(self)
+    }
+
+    // Check that the two sets of each SetEqual hold the same values once both are full.
+    pub(super) fn check_set_equals(&self) -> Result<(), SudokuError> {
+        for idx in 0..self.sets.count {
+            // Count each value up for the a side and down for the b side.
+            let (mut counts, mut full) = ([0isize; N], true);
+            for (ridx, row) in self.sets.of.iter().enumerate() {
+                for (cidx, &of) in row.iter().enumerate() {
+                    if of == 0 || (of as usize - 1) / 2 != idx {
+                        continue;
+                    }
+                    match self.cells[ridx][cidx] {
+                        Cell::Value(v) if (of - 1) % 2 == 0 => counts[v - 1] += 1,
+                        Cell::Value(v) => counts[v - 1] -= 1,
+                        Cell::Options(_) => full = false,
+                    }
+                }
+            }
+            if full && counts.iter().any(|&count| count != 0) {
+                return Err(SudokuError::SetsDiffer(idx));
+            }
+        }
+        Ok(())
+    }
+
+    // The cells a knight's move away from the given cell, leaving out those off the board.
+    pub(super) fn knight_peers(ridx: usize, cidx: usize) -> impl Iterator<Item = (usize, usize)> {
+        KNIGHT_MOVES.iter().filter_map(move |&(dr, dc)| {
+            let other_ridx = (ridx as isize + dr) as usize;
+            let other_cidx = (cidx as isize + dc) as usize;
+            // Moving off the top or left wraps around to a huge index, so this catches both
+            // sides.
+            if other_ridx < N && other_cidx < N {
+                Some((other_ridx, other_cidx))
+            } else {
+                None
+            }
+        })
+    }
+
+    // Check that no two cells a knight's move apart hold the same value.
+    pub(super) fn check_knight(&self) -> Result<(), SudokuError> {
+        for ridx in 0..N {
+            for cidx in 0..N {
+                if let Cell::Value(value) = self.cells[ridx][cidx] {
+                    for (other_ridx, other_cidx) in Self::knight_peers(ridx, cidx) {
+                        if self.cells[other_ridx][other_cidx] == Cell::Value(value) {
+                            return Err(SudokuError::Duplicate(value));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
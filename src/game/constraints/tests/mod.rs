@@ -0,0 +1,209 @@
+use super::*;
+
+const DIAGONALS: ExtraConstraints = ExtraConstraints {
+    diagonals: true,
+    knight: false,
+};
+
+const KNIGHT: ExtraConstraints = ExtraConstraints {
+    diagonals: false,
+    knight: true,
+};
+
+#[test]
+fn check_diagonal_duplicate() {
+    // This is valid under the standard rules, but the main diagonal holds two 1s.
+    let board = Board::new([
+        [1, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 1, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ])
+    .expect("building board literal");
+    board.check().expect("Failed to validate board.");
+
+    if let Ok(b) = board.with_constraints(DIAGONALS) {
+        panic!("wanted error due to diagonal duplicate, got: {:#?}", b)
+    }
+}
+
+#[test]
+fn check_solve_diagonals() {
+    let puzzle = Board::new([
+        [0, 0, 0, 0, 8, 1, 0, 0, 0],
+        [0, 4, 1, 0, 0, 0, 0, 0, 5],
+        [0, 2, 0, 0, 0, 0, 0, 0, 0],
+        [0, 7, 0, 0, 5, 0, 0, 0, 4],
+        [0, 0, 0, 9, 0, 0, 0, 8, 0],
+        [0, 0, 4, 2, 0, 0, 0, 0, 6],
+        [0, 0, 0, 0, 0, 0, 0, 0, 1],
+        [0, 5, 0, 4, 0, 3, 0, 0, 0],
+        [0, 0, 0, 0, 0, 5, 0, 0, 0],
+    ])
+    .expect("building board literal");
+
+    // Under the standard rules this has more than one solution, so it
+    // can't be solved as a (proper) puzzle.
+    assert!(!puzzle.has_unique_solution());
+
+    // With the diagonals constrained, the solution is unique.
+    let mut input = puzzle
+        .with_constraints(DIAGONALS)
+        .expect("applying constraints");
+    assert!(input.has_unique_solution());
+
+    let solution = Board::new([
+        [3, 6, 5, 7, 8, 1, 4, 2, 9],
+        [9, 4, 1, 3, 2, 6, 8, 7, 5],
+        [7, 2, 8, 5, 4, 9, 6, 1, 3],
+        [2, 7, 9, 6, 5, 8, 1, 3, 4],
+        [5, 3, 6, 9, 1, 4, 2, 8, 7],
+        [8, 1, 4, 2, 3, 7, 9, 5, 6],
+        [6, 9, 3, 8, 7, 2, 5, 4, 1],
+        [1, 5, 2, 4, 6, 3, 7, 9, 8],
+        [4, 8, 7, 1, 9, 5, 3, 6, 2],
+    ])
+    .expect("building board literal")
+    .with_constraints(DIAGONALS)
+    .expect("applying constraints");
+
+    input.solve().expect("error finding solution");
+    assert_eq!(input, solution);
+}
+
+#[test]
+fn check_knight_peers() {
+    // A corner has just two cells a knight's move away.
+    let mut peers: Vec<(usize, usize)> = Board::<9>::knight_peers(0, 0).collect();
+    peers.sort();
+    assert_eq!(peers, vec![(1, 2), (2, 1)]);
+
+    // An edge has four, and the middle of the board all eight.
+    assert_eq!(Board::<9>::knight_peers(4, 0).count(), 4);
+    assert_eq!(Board::<9>::knight_peers(4, 4).count(), 8);
+    assert_eq!(Board::<9>::knight_peers(8, 7).count(), 3);
+}
+
+#[test]
+fn check_knight_duplicate() {
+    // This is valid under the standard rules, but the 1s are a knight's move apart.
+    let board = Board::new([
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 1, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 1, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ])
+    .expect("building board literal");
+    board.check().expect("Failed to validate board.");
+
+    assert_eq!(
+        board.with_constraints(KNIGHT).err(),
+        Some(SudokuError::Duplicate(1))
+    );
+    // The diagonals don't care.
+    board
+        .with_constraints(DIAGONALS)
+        .expect("applying constraints");
+}
+
+#[test]
+fn check_solve_knight() {
+    // Solving keeps values off of the cells a knight's move away.
+    let mut input = Board::new([[0; 9]; 9])
+        .expect("building board literal")
+        .with_constraints(KNIGHT)
+        .expect("applying constraints");
+    input.solve().expect("error finding solution");
+    assert!(input.is_complete());
+    input.check().expect("Failed to validate board.");
+
+    // The candidates of a cell leave out the values of its knight's move peers.
+    let mut input = Board::new([[0; 9]; 9])
+        .expect("building board literal")
+        .with_constraints(KNIGHT)
+        .expect("applying constraints");
+    input.place(1, 3, 1).expect("placing value");
+    assert!(!input.candidates(3, 4).expect("cell on the board").has(1));
+    assert!(input.candidates(4, 4).expect("cell on the board").has(1));
+}
+
+#[test]
+fn check_set_equal() {
+    // The top-left pair of cells must hold the same values as a pair in the middle row.
+    let set_equal = || Constraint::SetEqual {
+        a: vec![(0, 0), (0, 1)],
+        b: vec![(4, 4), (4, 5)],
+    };
+    let mut values = [[0; 9]; 9];
+    values[0][0] = 1;
+    values[0][1] = 2;
+    values[4][4] = 2;
+
+    // A half-empty set can't break the constraint.
+    let board = Board::new(values)
+        .expect("building board literal")
+        .with_custom_constraints(vec![set_equal()])
+        .expect("partial sets are fine");
+
+    // Once both are full, the values must match, in any order.
+    let mut good = values;
+    good[4][5] = 1;
+    Board::new(good)
+        .expect("building board literal")
+        .with_custom_constraints(vec![set_equal()])
+        .expect("matching sets are fine");
+    let mut bad = values;
+    bad[4][5] = 3;
+    assert_eq!(
+        Board::new(bad)
+            .expect("building board literal")
+            .with_custom_constraints(vec![set_equal()])
+            .err(),
+        Some(SudokuError::SetsDiffer(0))
+    );
+
+    // The solver respects the constraint.
+    let mut solved = board;
+    solved.solve().expect("error finding solution");
+    assert_eq!(solved.cells[4][5], Cell::Value(1));
+}
+
+#[test]
+fn check_bad_set_equal() {
+    let board = Board::new([[0; 9]; 9]).expect("building board literal");
+    let constraints = [
+        // The sets differ in size.
+        Constraint::SetEqual {
+            a: vec![(0, 0)],
+            b: vec![(4, 4), (4, 5)],
+        },
+        // A cell strays off the board.
+        Constraint::SetEqual {
+            a: vec![(0, 0)],
+            b: vec![(9, 4)],
+        },
+        // A cell is in both sets.
+        Constraint::SetEqual {
+            a: vec![(0, 0), (1, 1)],
+            b: vec![(1, 1), (2, 2)],
+        },
+    ];
+    for constraint in constraints.iter() {
+        assert_eq!(
+            board
+                .with_custom_constraints(vec![constraint.clone()])
+                .err(),
+            Some(SudokuError::InvalidConstraint(0))
+        );
+    }
+}
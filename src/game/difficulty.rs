@@ -0,0 +1,76 @@
+use super::{Board, BoxLineReduction, CageSums, Cell, HiddenSingles, NakedSingles, Strategy, Unit};
+
+// How hard a puzzle is, based on the hardest technique needed to solve it.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub enum Difficulty {
+    Easy,       // Naked singles are enough.
+    Medium,     // Hidden singles are needed.
+    Hard,       // Eliminations are needed: box-line reduction, or cage sums on a Killer board.
+    Diabolical, // Logic alone won't do, so guessing is needed.
+}
+
+// How the clues of a puzzle are spread over the board, as a cheap hint at how hard it is.
+// Each spread is the (fewest, most) clues held by any one of those units.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ClueProfile {
+    pub total: usize,
+    pub rows: (usize, usize),
+    pub cols: (usize, usize),
+    pub boxes: (usize, usize),
+}
+
+impl<const N: usize> Board<N> {
+    // Rate how hard the board is to solve by trying progressively more advanced sets of
+    // strategies until one of them closes the puzzle without guessing.  Boards that can't be
+    // solved by logic (including those that can't be solved at all) are Diabolical.
+    pub fn difficulty(&self) -> Difficulty {
+        let tiers: [(Difficulty, &[&dyn Strategy<N>]); 3] = [
+            (Difficulty::Easy, &[&NakedSingles]),
+            (Difficulty::Medium, &[&NakedSingles, &HiddenSingles]),
+            (
+                Difficulty::Hard,
+                &[&NakedSingles, &HiddenSingles, &BoxLineReduction, &CageSums],
+            ),
+        ];
+        for (difficulty, strategies) in tiers.iter() {
+            // Work on a copy so that we don't change the caller's board.
+            let mut board = *self;
+            if let Ok(true) = board.solve_logically(strategies) {
+                return *difficulty;
+            }
+        }
+        Difficulty::Diabolical
+    }
+
+    // Summarize how the givens are spread over the board, without solving it.
+    pub fn clue_profile(&self) -> ClueProfile {
+        let spread = |unit: fn(usize) -> Unit| {
+            let counts = (0..N).map(|idx| {
+                (0..N)
+                    .filter(|&index| {
+                        let (ridx, cidx) = self.unit_cell(unit(idx), index);
+                        self.is_given(ridx, cidx)
+                    })
+                    .count()
+            });
+            (counts.clone().min().unwrap_or(0), counts.max().unwrap_or(0))
+        };
+        ClueProfile {
+            total: self.givens.iter().flatten().filter(|&&given| given).count(),
+            rows: spread(Unit::Row),
+            cols: spread(Unit::Col),
+            boxes: spread(Unit::Box),
+        }
+    }
+
+    // Whether the pattern of filled cells looks the same after rotating the board by 180
+    // degrees, as is traditional for hand-made puzzles.
+    pub fn has_rotational_symmetry(&self) -> bool {
+        let filled = |ridx: usize, cidx: usize| matches!(self.cells[ridx][cidx], Cell::Value(_));
+        (0..N)
+            .all(|ridx| (0..N).all(|cidx| filled(ridx, cidx) == filled(N - 1 - ridx, N - 1 - cidx)))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
@@ -0,0 +1,137 @@
+use super::*;
+use crate::game::fixtures::harder;
+use crate::game::Cage;
+
+#[test]
+fn check_super_easy() {
+    let board = Board::new([
+        [0, 4, 9, 7, 5, 1, 8, 6, 3],
+        [1, 0, 5, 8, 6, 9, 7, 4, 2],
+        [8, 6, 0, 2, 4, 3, 9, 5, 1],
+        [7, 8, 6, 0, 1, 2, 4, 9, 5],
+        [5, 2, 3, 9, 0, 4, 6, 1, 7],
+        [9, 1, 4, 5, 7, 0, 2, 3, 8],
+        [4, 7, 1, 6, 2, 5, 0, 8, 9],
+        [6, 9, 8, 1, 3, 7, 5, 0, 4],
+        [3, 5, 2, 4, 9, 8, 1, 7, 0],
+    ])
+    .expect("building board literal");
+    assert_eq!(board.difficulty(), Difficulty::Easy);
+}
+
+#[test]
+fn check_super_hard() {
+    let board = Board::new([
+        [0, 4, 0, 7, 0, 1, 0, 0, 3],
+        [1, 3, 0, 0, 0, 0, 0, 4, 0],
+        [8, 0, 0, 0, 0, 0, 9, 5, 0],
+        [0, 8, 0, 3, 0, 2, 0, 0, 5],
+        [0, 0, 0, 0, 8, 0, 0, 0, 0],
+        [9, 0, 0, 5, 0, 6, 0, 3, 0],
+        [0, 7, 1, 0, 0, 0, 0, 0, 9],
+        [0, 9, 0, 0, 0, 0, 0, 2, 4],
+        [3, 0, 0, 4, 0, 8, 0, 7, 0],
+    ])
+    .expect("building board literal");
+    let before = board;
+    assert_eq!(board.difficulty(), Difficulty::Diabolical);
+    // Rating the board doesn't change it.
+    assert_eq!(board, before);
+}
+
+#[test]
+fn check_box_line_reduction() {
+    let board = harder();
+    assert_eq!(board.difficulty(), Difficulty::Hard);
+}
+
+#[test]
+fn check_hardest() {
+    let board = Board::new([
+        [0, 0, 5, 3, 0, 0, 0, 0, 0],
+        [8, 0, 0, 0, 0, 0, 0, 2, 0],
+        [0, 7, 0, 0, 1, 0, 5, 0, 0],
+        [4, 0, 0, 0, 0, 5, 3, 0, 0],
+        [0, 1, 0, 0, 7, 0, 0, 0, 6],
+        [0, 0, 3, 2, 0, 0, 0, 8, 0],
+        [0, 6, 0, 5, 0, 0, 0, 0, 9],
+        [0, 0, 4, 0, 0, 0, 0, 3, 0],
+        [0, 0, 0, 0, 0, 9, 7, 0, 0],
+    ])
+    .expect("building board literal");
+    assert_eq!(board.difficulty(), Difficulty::Diabolical);
+}
+
+#[test]
+fn check_clue_profile() {
+    // The clues are bunched up at the top of the board.
+    let mut board = Board::new([
+        [1, 2, 3, 4, 5, 6, 7, 8, 9],
+        [4, 5, 6, 0, 0, 0, 0, 0, 0],
+        [0; 9],
+        [0; 9],
+        [0, 0, 0, 0, 0, 0, 0, 0, 1],
+        [0; 9],
+        [0; 9],
+        [0; 9],
+        [0; 9],
+    ])
+    .expect("building board literal");
+    let profile = ClueProfile {
+        total: 13,
+        rows: (0, 9),
+        cols: (1, 2),
+        boxes: (0, 6),
+    };
+    assert_eq!(board.clue_profile(), profile);
+
+    // Solving the board doesn't add clues.
+    board
+        .solve_with_min_clues(0)
+        .expect("error finding solution");
+    assert_eq!(board.clue_profile(), profile);
+}
+
+#[test]
+fn check_rotational_symmetry() {
+    let board = Board::<9>::parse_line(
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079",
+    )
+    .expect("building board line");
+    assert!(board.has_rotational_symmetry());
+
+    // Blanking one clue breaks the symmetry.
+    let board = Board::<9>::parse_line(
+        "030070000600195000098000060800060003400803001700020006060000280000419005000080079",
+    )
+    .expect("building board line");
+    assert!(!board.has_rotational_symmetry());
+
+    // An empty board is trivially symmetric.
+    assert!(Board::new([[0; 9]; 9])
+        .expect("building board literal")
+        .has_rotational_symmetry());
+}
+
+#[test]
+fn check_killer() {
+    // On its own this 4x4 puzzle needs guessing, but the sums of its cages pin it down.  Cage
+    // sums eliminate options much as box-line reduction does, so they rate the same.
+    let puzzle =
+        Board::new([[1, 0, 0, 4], [0; 4], [0, 1, 0, 0], [0; 4]]).expect("building board literal");
+    assert_eq!(puzzle.difficulty(), Difficulty::Diabolical);
+
+    let killer = puzzle
+        .with_cages(vec![
+            Cage {
+                cells: vec![(0, 0), (1, 0)],
+                sum: 4,
+            },
+            Cage {
+                cells: vec![(2, 2), (2, 3), (3, 3)],
+                sum: 8,
+            },
+        ])
+        .expect("attaching cages");
+    assert_eq!(killer.difficulty(), Difficulty::Hard);
+}
@@ -0,0 +1,225 @@
+use super::Unit;
+use core::fmt;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SudokuError {
+    // A value outside of 0 to the size of the board.
+    InvalidValue {
+        row: usize,
+        col: usize,
+        value: usize,
+    },
+    // A value outside of 0 to the size of the board, at the given index of a flat slice.
+    InvalidEntry {
+        index: usize,
+        value: usize,
+    },
+    // A cell outside of the board.
+    OutOfBounds {
+        row: usize,
+        col: usize,
+    },
+    // A cell that already holds a value, so nothing more can be placed in it.
+    AlreadySolved {
+        row: usize,
+        col: usize,
+    },
+    // A row, column, or box outside of the board.
+    InvalidUnit(Unit),
+    // A value that can never be one of a cell's options.
+    InvalidOption(usize),
+    // A character parse doesn't understand.
+    InvalidCharacter(char),
+    // The input has the wrong number of rows.
+    RowCount {
+        rows: usize,
+        wanted: usize,
+    },
+    // A line holding only whitespace, which throws off the row count.
+    BlankLine(usize),
+    // A row has the wrong number of columns.
+    ColumnCount {
+        row: usize,
+        columns: usize,
+        wanted: usize,
+    },
+    // A region id outside of 0 to one less than the size of the board.
+    InvalidRegion {
+        row: usize,
+        col: usize,
+        region: usize,
+    },
+    // A region has the wrong number of cells.
+    RegionSize {
+        region: usize,
+        cells: usize,
+        wanted: usize,
+    },
+    // Boxes of the given shape don't tile a board of the given size.
+    BoxShape {
+        rows: usize,
+        cols: usize,
+        size: usize,
+    },
+    // A single-line board has the wrong number of cells.
+    LineLength {
+        length: usize,
+        wanted: usize,
+    },
+    // A grid has the wrong number of cells once its separators are removed.
+    CellCount {
+        cells: usize,
+        wanted: usize,
+    },
+    // The input couldn't be read, e.g. because it wasn't UTF-8.
+    #[cfg(feature = "std")]
+    Read(std::io::ErrorKind),
+    // A cage is empty, strays off the board, or shares a cell with another cage.
+    InvalidCage(usize),
+    // The values in a cage add up to more than its sum, or don't reach it once it is full.
+    CageSum {
+        cage: usize,
+        sum: usize,
+        wanted: usize,
+    },
+    // A custom constraint is malformed, e.g. its cells stray off the board.
+    InvalidConstraint(usize),
+    // The two sets of cells of a SetEqual constraint hold different values.
+    SetsDiffer(usize),
+    // A value given for a cell is already given for one of its peers.
+    Clash {
+        row: usize,
+        col: usize,
+        value: usize,
+        peer_row: usize,
+        peer_col: usize,
+    },
+    // A value appears more than once in a row, column, or region.
+    Duplicate(usize),
+    // The solver placed a value twice in the unit, showing that the board is contradictory.
+    Conflict {
+        value: usize,
+        unit: Unit,
+    },
+    // A cell has no remaining options.
+    NoOptions {
+        row: usize,
+        col: usize,
+    },
+    // The board has too few values to be worth searching for a solution.
+    Underdetermined {
+        clues: usize,
+        wanted: usize,
+    },
+    // The board has no solution: every value tried while speculating led to failure, or it is
+    // contradictory in a way that can't be pinned on a cell or unit.
+    Unsolvable,
+    // The solver claimed success but left the board incomplete or breaking the rules, which
+    // is a bug in the solver.
+    InternalInconsistency,
+    // The solver ran out of iterations, so it can't say whether the board has a solution.
+    DidNotConverge,
+}
+
+impl fmt::Display for SudokuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SudokuError::InvalidValue { row, col, value } => {
+                write!(f, "Invalid value ({}, {}) = {}", row, col, value)
+            }
+            SudokuError::InvalidEntry { index, value } => {
+                write!(f, "Invalid value at index {} = {}", index, value)
+            }
+            SudokuError::OutOfBounds { row, col } => {
+                write!(f, "Cell ({}, {}) is off the board", row, col)
+            }
+            SudokuError::AlreadySolved { row, col } => {
+                write!(f, "Cell ({}, {}) already holds a value", row, col)
+            }
+            SudokuError::InvalidUnit(unit) => write!(f, "{:?} is off the board", unit),
+            SudokuError::InvalidOption(v) => write!(f, "{} is not a valid option", v),
+            SudokuError::InvalidCharacter(c) => write!(f, "Found invalid input character: {}", c),
+            SudokuError::RowCount { rows, wanted } => {
+                write!(f, "input has {} rows, wanted {}", rows, wanted)
+            }
+            SudokuError::BlankLine(line) => {
+                write!(
+                    f,
+                    "line {} is blank; remove the blank lines in the input",
+                    line
+                )
+            }
+            SudokuError::ColumnCount {
+                row,
+                columns,
+                wanted,
+            } => write!(f, "row {} has {} columns, wanted {}", row, columns, wanted),
+            SudokuError::BoxShape { rows, cols, size } => write!(
+                f,
+                "boxes of {}x{} cells don't tile a board of size {}",
+                rows, cols, size
+            ),
+            SudokuError::LineLength { length, wanted } => {
+                write!(f, "line has {} cells, wanted {}", length, wanted)
+            }
+            SudokuError::CellCount { cells, wanted } => {
+                write!(f, "grid has {} cells, wanted {}", cells, wanted)
+            }
+            SudokuError::InvalidRegion { row, col, region } => {
+                write!(f, "Invalid region ({}, {}) = {}", row, col, region)
+            }
+            SudokuError::RegionSize {
+                region,
+                cells,
+                wanted,
+            } => write!(
+                f,
+                "region {} has {} cells, wanted {}",
+                region, cells, wanted
+            ),
+            #[cfg(feature = "std")]
+            SudokuError::Read(kind) => write!(f, "unable to read input: {}", kind),
+            SudokuError::InvalidCage(cage) => write!(f, "Invalid cage {}", cage),
+            SudokuError::CageSum { cage, sum, wanted } => {
+                write!(f, "cage {} sums to {}, wanted {}", cage, sum, wanted)
+            }
+            SudokuError::InvalidConstraint(idx) => write!(f, "Invalid constraint {}", idx),
+            SudokuError::SetsDiffer(idx) => {
+                write!(f, "constraint {} has sets holding different values", idx)
+            }
+            SudokuError::Clash {
+                row,
+                col,
+                value,
+                peer_row,
+                peer_col,
+            } => write!(
+                f,
+                "({}, {}) = {} clashes with the same value at ({}, {})",
+                row, col, value, peer_row, peer_col
+            ),
+            SudokuError::Duplicate(v) => write!(f, "Multiple {} seen", v),
+            SudokuError::Conflict { value, unit } => {
+                write!(f, "{:?} holds more than one {}", unit, value)
+            }
+            SudokuError::NoOptions { row, col } => {
+                write!(f, "There are no remaining options for {}, {}", row, col)
+            }
+            SudokuError::Underdetermined { clues, wanted } => write!(
+                f,
+                "board has {} values, too few to solve (wanted at least {})",
+                clues, wanted
+            ),
+            SudokuError::Unsolvable => f.write_str("The board has no solution"),
+            SudokuError::InternalInconsistency => {
+                f.write_str("The solver left the board incomplete or invalid")
+            }
+            SudokuError::DidNotConverge => {
+                f.write_str("Solution did not converge within the limit on passes")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SudokuError {}
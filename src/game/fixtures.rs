@@ -0,0 +1,45 @@
+// Boards and helpers shared by the tests of several modules.
+use super::{Board, Strategy, SudokuError, Technique};
+
+// This needs box-line reduction to solve without guessing.
+pub(super) fn harder() -> Board {
+    Board::new([
+        [0, 0, 0, 0, 0, 5, 4, 3, 0],
+        [0, 0, 4, 0, 0, 0, 0, 6, 9],
+        [0, 0, 1, 3, 0, 0, 0, 0, 0],
+        [0, 0, 0, 8, 7, 9, 0, 4, 0],
+        [6, 0, 0, 2, 3, 0, 0, 0, 0],
+        [0, 0, 0, 0, 6, 0, 0, 0, 8],
+        [0, 0, 6, 0, 0, 0, 0, 0, 7],
+        [1, 5, 0, 0, 0, 0, 0, 8, 0],
+        [0, 3, 0, 9, 0, 0, 0, 0, 0],
+    ])
+    .expect("building board literal")
+}
+
+// Apply the strategies until they stop making progress, returning the
+// number of cells that remain unsolved.
+pub(super) fn stall(board: &mut Board, strategies: &[&dyn Strategy]) -> u32 {
+    loop {
+        let (options, changed) = board
+            .solve_one_with(strategies)
+            .expect("error during solve_one_with");
+        if options == 0 || !changed {
+            return options;
+        }
+    }
+}
+
+// A custom strategy that changes nothing, counting how often it is applied.
+pub(super) struct Counter(pub(super) std::cell::Cell<usize>);
+
+impl Strategy for Counter {
+    fn apply(&self, _board: &mut Board) -> Result<bool, SudokuError> {
+        self.0.set(self.0.get() + 1);
+        Ok(false)
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::NakedSingle
+    }
+}
@@ -0,0 +1,166 @@
+use super::bitset::BitSet;
+use super::{Board, Cell};
+
+// Rng is the source of randomness used to generate boards.  Implement it to plug in another
+// generator (e.g. one from the rand crate).
+pub trait Rng {
+    fn next_u64(&mut self) -> u64;
+}
+
+// Shuffle the values in place (Fisher-Yates).
+fn shuffle(rng: &mut impl Rng, values: &mut [usize]) {
+    for i in (1..values.len()).rev() {
+        let j = (rng.next_u64() % (i as u64 + 1)) as usize;
+        values.swap(i, j);
+    }
+}
+
+// A small xorshift pseudo-random number generator, which is plenty for shuffling and keeps
+// us free of dependencies.
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    pub fn new(seed: u64) -> XorShift {
+        // Xorshift gets stuck on a zero state, so substitute an arbitrary non-zero one.
+        XorShift {
+            state: if seed == 0 {
+                0x9E37_79B9_7F4A_7C15
+            } else {
+                seed
+            },
+        }
+    }
+}
+
+impl Rng for XorShift {
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+}
+
+// Generation depends only on the seed (or the given Rng) and our own code: there is no hashing,
+// system randomness, or floating point involved, so a seed produces the same board on every
+// machine and Rust version.  Changing what a seed produces breaks fixtures built from it, so
+// test_generate_golden pins down an example.
+impl Board {
+    // Generate a random, completely solved board.  The same seed always produces the same board.
+    pub fn generate_solved(seed: u64) -> Board {
+        Board::generate_solved_with(&mut XorShift::new(seed))
+    }
+
+    // Generate a random, completely solved board using the given source of randomness.
+    pub fn generate_solved_with(rng: &mut impl Rng) -> Board {
+        let mut board = Board::new([[0; 9]; 9]).expect("an empty board is valid");
+        if !board.fill(0, rng) {
+            unreachable!("every partial board built by fill can be completed");
+        }
+        board.mark_givens();
+        board
+    }
+
+    // Generate a random puzzle with a unique solution by removing values from a random solved
+    // board, in a random order, for as long as the solution stays unique.  This stops once
+    // the puzzle is down to target_clues values, or when no more can be removed.
+    pub fn generate_puzzle(seed: u64, target_clues: usize) -> Board {
+        Board::generate_puzzle_with(&mut XorShift::new(seed), target_clues)
+    }
+
+    // Generate a random puzzle as above, using the given source of randomness.
+    pub fn generate_puzzle_with(rng: &mut impl Rng, target_clues: usize) -> Board {
+        let mut board = Board::generate_solved_with(rng);
+
+        let mut order = [0; 81];
+        for (index, elt) in order.iter_mut().enumerate() {
+            *elt = index;
+        }
+        shuffle(rng, &mut order);
+
+        let all = BitSet::full();
+        let mut clues = 81;
+        for index in order.iter() {
+            if clues <= target_clues {
+                break;
+            }
+            let (ridx, cidx) = (index / 9, index % 9);
+            let value = board.cells[ridx][cidx];
+            board.put(ridx, cidx, Cell::Options(all));
+            if board.has_unique_solution() {
+                clues -= 1;
+            } else {
+                // Removing this value admits other solutions, so put it back.
+                board.put(ridx, cidx, value);
+            }
+        }
+        board.mark_givens();
+        board
+    }
+
+    // Fill the cells from index onwards (in row-major order) with values tried in a random
+    // order, backtracking when a cell has no options left.  Returns whether it succeeded.
+    fn fill(&mut self, index: usize, rng: &mut impl Rng) -> bool {
+        if index == 81 {
+            return true;
+        }
+        let (ridx, cidx) = (index / 9, index % 9);
+        let original = self.cells[ridx][cidx];
+        let opts = self.options(ridx, cidx);
+
+        let mut values = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        shuffle(rng, &mut values);
+        for &value in values.iter().filter(|&&v| opts.has(v)) {
+            self.put(ridx, cidx, Cell::Value(value));
+            if self.fill(index + 1, rng) {
+                return true;
+            }
+        }
+        self.put(ridx, cidx, original);
+        false
+    }
+}
+
+impl<const N: usize> Board<N> {
+    // Remove every given we can, one at a time in row-major order, keeping each removal only if
+    // the solution stays unique.  The result has the same solution, but no given that could be
+    // removed on its own.  It is minimal for that order, but another order may remove more.
+    pub fn minimize(&self) -> Board<N> {
+        // Start from the givens alone, since placed values and narrowed options would hide
+        // what removing a given lets back in.
+        let mut board = *self;
+        board.reset();
+        for ridx in 0..N {
+            for cidx in 0..N {
+                if !board.givens[ridx][cidx] {
+                    continue;
+                }
+                let value = board.cells[ridx][cidx];
+                board.put(ridx, cidx, Cell::Options(BitSet::all(N)));
+                if board.has_unique_solution() {
+                    board.givens[ridx][cidx] = false;
+                } else {
+                    // The clue is needed, so put it back.
+                    board.put(ridx, cidx, value);
+                }
+            }
+        }
+        board
+    }
+
+    // Mark exactly the cells holding values as givens, as if the board had been built by new.
+    fn mark_givens(&mut self) {
+        for (givens, row) in self.givens.iter_mut().zip(self.cells.iter()) {
+            for (given, cell) in givens.iter_mut().zip(row.iter()) {
+                *given = matches!(cell, Cell::Value(_));
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
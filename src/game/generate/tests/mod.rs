@@ -0,0 +1,160 @@
+use super::*;
+
+#[test]
+fn test_generate_solved() {
+    let board = Board::generate_solved(42);
+    board.check().expect("Failed to validate board.");
+    assert!(board.is_complete());
+
+    // The same seed produces the same board.
+    assert_eq!(board, Board::generate_solved(42));
+    // A different seed (very likely) produces a different one.
+    assert_ne!(board, Board::generate_solved(43));
+}
+
+#[test]
+fn test_generate_solved_zero_seed() {
+    let board = Board::generate_solved(0);
+    board.check().expect("Failed to validate board.");
+    assert!(board.is_complete());
+}
+
+#[test]
+fn test_generate_puzzle() {
+    let puzzle = Board::generate_puzzle(42, 30);
+    puzzle.check().expect("Failed to validate board.");
+    assert!(puzzle.has_unique_solution());
+
+    // We may not be able to dig all the way down to the target, but we
+    // should get close, and never go below it.
+    let clues = puzzle.count_filled();
+    assert!(clues >= 30, "too few clues: {}", clues);
+    assert!(clues <= 35, "too many clues: {}", clues);
+
+    // The remaining values are the givens.
+    for ridx in 0..9 {
+        for cidx in 0..9 {
+            let filled = matches!(puzzle.cells[ridx][cidx], Cell::Value(_));
+            assert_eq!(puzzle.is_given(ridx, cidx), filled);
+        }
+    }
+
+    // Solving the puzzle reproduces the board it was dug out of.
+    let mut solved = puzzle;
+    solved.solve().expect("error finding solution");
+    assert_eq!(solved, Board::generate_solved(42));
+}
+
+#[test]
+fn test_generate_golden() {
+    // These must never change, as callers rely on seeds for reproducible fixtures.
+    assert_eq!(
+        Board::generate_solved(42).to_line(),
+        "679548213213976548485312976857164392142893657396257481731425869924681735568739124"
+    );
+    assert_eq!(
+        Board::generate_puzzle(42, 30).to_line(),
+        "000500000000076008005010000050064300140803657390200000030025800004000705060030100"
+    );
+}
+
+#[test]
+fn test_has_unique_solution() {
+    // A solved board has exactly one solution.
+    assert!(Board::generate_solved(7).has_unique_solution());
+    // An empty board has many.
+    assert!(!Board::new([[0; 9]; 9])
+        .expect("building board literal")
+        .has_unique_solution());
+}
+
+// A fake Rng that cycles through a fixed sequence of numbers.
+struct Sequence {
+    values: Vec<u64>,
+    index: usize,
+}
+
+impl Rng for Sequence {
+    fn next_u64(&mut self) -> u64 {
+        let value = self.values[self.index % self.values.len()];
+        self.index += 1;
+        value
+    }
+}
+
+#[test]
+fn test_generate_with_rng() {
+    let mut rng = Sequence {
+        values: vec![0],
+        index: 0,
+    };
+    let board = Board::generate_solved_with(&mut rng);
+
+    // A fixed sequence makes the shuffles, and so the board, predictable.
+    let want = Board::new([
+        [2, 3, 4, 5, 6, 7, 8, 9, 1],
+        [5, 6, 7, 8, 9, 1, 2, 3, 4],
+        [8, 9, 1, 2, 3, 4, 5, 6, 7],
+        [3, 2, 5, 4, 7, 6, 9, 1, 8],
+        [4, 7, 6, 9, 1, 8, 3, 2, 5],
+        [9, 1, 8, 3, 2, 5, 4, 7, 6],
+        [6, 4, 2, 7, 5, 3, 1, 8, 9],
+        [7, 5, 3, 1, 8, 9, 6, 4, 2],
+        [1, 8, 9, 6, 4, 2, 7, 5, 3],
+    ])
+    .expect("building board literal");
+    assert_eq!(board, want);
+}
+
+#[test]
+fn test_minimize() {
+    // A minimal puzzle, plus two clues at the top left that it doesn't need.
+    let minimal = Board::<9>::parse_line(
+        "004000000000195000098000060000060003400800001700020000060000280000409005000080070",
+    )
+    .expect("building board line");
+    let padded = Board::<9>::parse_line(
+        "534000000000195000098000060000060003400800001700020000060000280000409005000080070",
+    )
+    .expect("building board line");
+
+    let minimized = padded.minimize();
+    assert_eq!(minimized.to_line(), minimal.to_line());
+    assert!(minimized.has_unique_solution());
+    assert!(!minimized.is_given(0, 0));
+    assert!(minimized.is_given(0, 2));
+
+    // The solution is unchanged.
+    let (mut want, mut got) = (padded, minimized);
+    want.solve().expect("error finding solution");
+    got.solve().expect("error finding solution");
+    assert_eq!(got.to_line(), want.to_line());
+
+    // There's nothing left to remove from a minimal puzzle.
+    assert_eq!(minimal.minimize().to_line(), minimal.to_line());
+}
+
+#[test]
+fn test_minimize_worked_board() {
+    let puzzle = Board::<9>::parse_line(
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079",
+    )
+    .expect("building board line");
+    let fresh = puzzle.minimize();
+
+    // Narrowed options and placed values don't change what can be removed.
+    let mut worked = puzzle;
+    worked.fill_candidates();
+    worked.solve_one().expect("error during solve_one");
+    let minimized = worked.minimize();
+    assert_eq!(minimized.to_line(), fresh.to_line());
+    assert!(minimized.has_unique_solution());
+    let reparsed = Board::<9>::parse_line(&minimized.to_line()).expect("building board line");
+    assert!(reparsed.has_unique_solution());
+
+    // The solution is unchanged.
+    let (mut want, mut got) = (puzzle, minimized);
+    want.solve().expect("error finding solution");
+    got.solve().expect("error finding solution");
+    assert_eq!(got.to_line(), want.to_line());
+}
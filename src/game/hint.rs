@@ -0,0 +1,74 @@
+use alloc::vec::Vec;
+
+use super::{Board, Cell, Technique};
+
+// A Hint describes a single logical deduction: that value belongs in the cell at row, col.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hint {
+    pub row: usize,
+    pub col: usize,
+    pub value: usize,
+    pub technique: Technique,
+}
+
+impl<const N: usize> Board<N> {
+    // Suggest the most basic deduction available on the board without changing it, trying
+    // naked singles before hidden singles.  Returns None if no such deduction exists.
+    pub fn hint(&self) -> Option<Hint> {
+        self.naked_single().or_else(|| self.hidden_single())
+    }
+
+    // The (row, column, value) of every unsolved cell with a single candidate, i.e. every
+    // naked single available right now, without changing the board.
+    pub fn forced_cells(&self) -> Vec<(usize, usize, usize)> {
+        let mut forced = Vec::new();
+        for ridx in 0..N {
+            for cidx in 0..N {
+                if let Cell::Options(_) = self.cells[ridx][cidx] {
+                    if let Some(value) = self.unplaced(ridx, cidx).singleton() {
+                        forced.push((ridx, cidx, value));
+                    }
+                }
+            }
+        }
+        forced
+    }
+
+    // Find the first cell that has only a single option remaining.
+    fn naked_single(&self) -> Option<Hint> {
+        for ridx in 0..N {
+            for cidx in 0..N {
+                if let Some(value) = self.options(ridx, cidx).singleton() {
+                    return Some(Hint {
+                        row: ridx,
+                        col: cidx,
+                        value,
+                        technique: Technique::NakedSingle,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    // Find the first cell that is the only one in its row, column, or region with an
+    // option for some value.
+    fn hidden_single(&self) -> Option<Hint> {
+        for ridx in 0..N {
+            for cidx in 0..N {
+                if let Some(value) = self.hidden_single_value(ridx, cidx) {
+                    return Some(Hint {
+                        row: ridx,
+                        col: cidx,
+                        value,
+                        technique: Technique::HiddenSingle,
+                    });
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
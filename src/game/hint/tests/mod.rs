@@ -0,0 +1,87 @@
+use super::*;
+use crate::game::fixtures::{harder, stall};
+use crate::game::{Cell, HiddenSingles, NakedSingles};
+
+#[test]
+fn test_naked_single() {
+    let board = Board::new([
+        [0, 4, 9, 7, 5, 1, 8, 6, 3],
+        [1, 0, 5, 8, 6, 9, 7, 4, 2],
+        [8, 6, 0, 2, 4, 3, 9, 5, 1],
+        [7, 8, 6, 0, 1, 2, 4, 9, 5],
+        [5, 2, 3, 9, 0, 4, 6, 1, 7],
+        [9, 1, 4, 5, 7, 0, 2, 3, 8],
+        [4, 7, 1, 6, 2, 5, 0, 8, 9],
+        [6, 9, 8, 1, 3, 7, 5, 0, 4],
+        [3, 5, 2, 4, 9, 8, 1, 7, 0],
+    ])
+    .expect("building board literal");
+    let before = board;
+
+    assert_eq!(
+        board.hint(),
+        Some(Hint {
+            row: 0,
+            col: 0,
+            value: 2,
+            technique: Technique::NakedSingle,
+        })
+    );
+    // Asking for a hint doesn't change the board.
+    assert_eq!(board, before);
+}
+
+#[test]
+fn test_hidden_single() {
+    // Once naked singles have stalled, only hidden singles remain.
+    let mut board = harder();
+    stall(&mut board, &[&NakedSingles]);
+
+    let hint = board.hint().expect("wanted a hint");
+    assert_eq!(hint.technique, Technique::HiddenSingle);
+
+    // The hint agrees with the solution.
+    let mut solution = harder();
+    solution.solve().expect("error finding solution");
+    assert_eq!(solution.cells[hint.row][hint.col], Cell::Value(hint.value));
+}
+
+#[test]
+fn test_stalled() {
+    // Once singles have stalled, there is nothing to hint.
+    let mut board = harder();
+    stall(&mut board, &[&NakedSingles, &HiddenSingles]);
+    assert_eq!(board.hint(), None);
+}
+
+#[test]
+fn test_forced_cells() {
+    // Two blanks that don't share a row, column, or subsquare can each hold only one value.
+    let board = Board::new([
+        [0, 4, 9, 7, 5, 1, 8, 6, 3],
+        [1, 3, 5, 8, 6, 9, 7, 4, 2],
+        [8, 6, 7, 2, 4, 3, 9, 5, 1],
+        [7, 8, 6, 3, 1, 2, 4, 9, 5],
+        [5, 2, 3, 9, 0, 4, 6, 1, 7],
+        [9, 1, 4, 5, 7, 6, 2, 3, 8],
+        [4, 7, 1, 6, 2, 5, 3, 8, 9],
+        [6, 9, 8, 1, 3, 7, 5, 2, 4],
+        [3, 5, 2, 4, 9, 8, 1, 7, 6],
+    ])
+    .expect("building board literal");
+    assert_eq!(board.forced_cells(), vec![(0, 0, 2), (4, 4, 8)]);
+
+    // On a puzzle, only some cells are forced, and they agree with the solution.
+    let board = Board::<9>::parse_line(
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079",
+    )
+    .expect("building board line");
+    let mut solution = board;
+    solution.solve().expect("error finding solution");
+    let forced = board.forced_cells();
+    assert!(!forced.is_empty());
+    assert!(forced.len() < 81 - board.count_filled());
+    for (row, col, value) in forced {
+        assert_eq!(solution.cells[row][col], Cell::Value(value));
+    }
+}
@@ -0,0 +1,66 @@
+use alloc::vec::Vec;
+
+use super::{Board, Snapshot, SudokuError};
+
+// A board being played interactively, which remembers the placements made on it so that they
+// can be undone and redone.  Boards are Copy, so each snapshot is a cheap copy of the cells.
+pub struct Game<const N: usize = 9> {
+    board: Board<N>,
+    undo: Vec<Snapshot<N>>, // The states before each placement, most recent last.
+    redo: Vec<Snapshot<N>>, // The states undone, most recent last.
+}
+
+impl<const N: usize> Game<N> {
+    pub fn new(board: Board<N>) -> Game<N> {
+        Game {
+            board,
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    pub fn board(&self) -> &Board<N> {
+        &self.board
+    }
+
+    // Place value in the given cell as Board::place does, remembering the prior state.  A new
+    // placement discards anything that was undone.  Failed placements leave the board as it
+    // was.
+    pub fn place(&mut self, row: usize, col: usize, value: usize) -> Result<(), SudokuError> {
+        let saved = self.board.snapshot();
+        if let Err(err) = self.board.place(row, col, value) {
+            self.board.restore(&saved);
+            return Err(err);
+        }
+        self.undo.push(saved);
+        self.redo.clear();
+        Ok(())
+    }
+
+    // Undo the last placement, returning whether there was one.
+    pub fn undo(&mut self) -> bool {
+        match self.undo.pop() {
+            Some(saved) => {
+                self.redo.push(self.board.snapshot());
+                self.board.restore(&saved);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Redo the last placement undone, returning whether there was one.
+    pub fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some(saved) => {
+                self.undo.push(self.board.snapshot());
+                self.board.restore(&saved);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,55 @@
+use super::*;
+
+fn puzzle() -> Board {
+    Board::<9>::parse_line(
+        "005300000800000020070010500400005300010070006003200080060500009004000030000009700",
+    )
+    .expect("building board line")
+}
+
+#[test]
+fn check_undo_redo() {
+    let mut before = puzzle();
+    before.fill_candidates();
+    let mut game = Game::new(before);
+
+    // Nothing to undo or redo yet.
+    assert!(!game.undo());
+    assert!(!game.redo());
+
+    game.place(4, 3, 8).expect("placing candidate");
+    let after = *game.board();
+    assert_ne!(after, before);
+
+    // Undoing restores the exact prior state, including the peers' candidates.
+    assert!(game.undo());
+    assert_eq!(game.board().cells, before.cells);
+    assert!(!game.undo());
+
+    // Redoing re-applies the placement.
+    assert!(game.redo());
+    assert_eq!(game.board().cells, after.cells);
+    assert!(!game.redo());
+
+    // A new placement discards what was undone.
+    assert!(game.undo());
+    game.place(4, 2, 9).expect("placing candidate");
+    assert!(!game.redo());
+}
+
+#[test]
+fn check_failed_place() {
+    let mut game = Game::new(puzzle());
+
+    // Breaking the rules leaves the board alone, with nothing to undo.
+    assert_eq!(game.place(0, 0, 5), Err(SudokuError::Duplicate(5)));
+    assert_eq!(game.board().cells, puzzle().cells);
+    assert!(!game.undo());
+
+    // As does a cell off the board.
+    assert_eq!(
+        game.place(9, 0, 1),
+        Err(SudokuError::OutOfBounds { row: 9, col: 0 })
+    );
+    assert_eq!(game.board().cells, puzzle().cells);
+}
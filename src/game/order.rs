@@ -0,0 +1,47 @@
+use super::bitset::BitSet;
+use super::Board;
+
+// The order in which the solver tries the options of a cell it is guessing at.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ValueOrder {
+    Ascending,         // Smallest value first.
+    LeastConstraining, // The value that the fewest of the cell's peers could hold first.
+}
+
+impl<const N: usize> Board<N> {
+    // Choose which of the remaining options to try next for the given cell.
+    pub(super) fn next_option(
+        &self,
+        ridx: usize,
+        cidx: usize,
+        remaining: BitSet,
+        order: ValueOrder,
+    ) -> Option<usize> {
+        match order {
+            ValueOrder::Ascending => remaining.foreach().next(),
+            // Placing a value removes it from the options of the cell's peers, so the value
+            // that the fewest of them could hold leaves the most room for the rest.
+            ValueOrder::LeastConstraining => remaining
+                .foreach()
+                .min_by_key(|&value| self.peers_with_option(ridx, cidx, value)),
+        }
+    }
+
+    // The number of the given cell's peers that have value as an option.
+    fn peers_with_option(&self, ridx: usize, cidx: usize, value: usize) -> usize {
+        let mut count = 0;
+        for other_ridx in 0..N {
+            for other_cidx in 0..N {
+                if self.is_peer(ridx, cidx, other_ridx, other_cidx)
+                    && self.options(other_ridx, other_cidx).has(value)
+                {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
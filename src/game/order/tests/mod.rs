@@ -0,0 +1,67 @@
+use super::*;
+
+#[test]
+fn check_least_constraining() {
+    // Arto Inkala's "Everest" puzzle, which needs a lot of guessing.
+    let puzzle = Board::<9>::parse_line(
+        "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+    )
+    .expect("building board line");
+
+    let mut ascending = puzzle;
+    let ascending_guesses = ascending
+        .solve_with_order(Board::<9>::DEFAULT_STRATEGIES, ValueOrder::Ascending)
+        .expect("error finding solution");
+
+    let mut least_constraining = puzzle;
+    let least_constraining_guesses = least_constraining
+        .solve_with_order(
+            Board::<9>::DEFAULT_STRATEGIES,
+            ValueOrder::LeastConstraining,
+        )
+        .expect("error finding solution");
+
+    // The order of the guesses doesn't change the answer, but trying the least constraining
+    // values first leads us to it sooner.
+    least_constraining
+        .check()
+        .expect("Failed to validate board.");
+    assert!(least_constraining.is_complete());
+    assert_eq!(least_constraining, ascending);
+    assert!(
+        least_constraining_guesses < ascending_guesses,
+        "{} guesses is not fewer than {}",
+        least_constraining_guesses,
+        ascending_guesses
+    );
+}
+
+#[test]
+fn check_next_option() {
+    let board = Board::<9>::parse_line(
+        "005300000800000020070010500400005300010070006003200080060500009004000030000009700",
+    )
+    .expect("building board line");
+    let opts = board.candidates(0, 0).expect("cell on the board");
+    assert_eq!(opts, BitSet::new(&[1, 2, 6, 9]));
+
+    assert_eq!(
+        board.next_option(0, 0, opts, ValueOrder::Ascending),
+        Some(1)
+    );
+    // Of the top-left cell's peers, 7 could hold a 1, 9 could hold a 2, 8 could hold a 6, and
+    // 11 could hold a 9.
+    let counts: Vec<usize> = opts
+        .foreach()
+        .map(|value| board.peers_with_option(0, 0, value))
+        .collect();
+    assert_eq!(counts, vec![7, 9, 8, 11]);
+    assert_eq!(
+        board.next_option(0, 0, opts.unset(1), ValueOrder::LeastConstraining),
+        Some(6)
+    );
+    assert_eq!(
+        board.next_option(0, 0, BitSet::new(&[]), ValueOrder::LeastConstraining),
+        None
+    );
+}
@@ -0,0 +1,138 @@
+use super::{Board, SudokuError};
+
+// The regions of the board that must each hold the values 1 to N exactly once.  On a standard
+// board these are the subsquares, but Jigsaw Sudoku replaces them with irregular shapes.
+// Besides the region of each cell, we keep the cells of each region so that a region can be
+// visited without scanning the whole board.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Regions<const N: usize> {
+    of: [[u8; N]; N],          // The region each cell belongs to.
+    cells: [[(u8, u8); N]; N], // The (row, column) of each cell in each region.
+}
+
+impl<const N: usize> Regions<N> {
+    // The standard regions: the subsquares, numbered left to right and then top to bottom.
+    pub fn standard() -> Regions<N> {
+        Regions::rectangular(Board::<N>::BOX_ROWS, Board::<N>::BOX_COLS)
+            .expect("subsquares are valid regions")
+    }
+
+    // Rectangular boxes of box_rows by box_cols cells, numbered left to right and then top to
+    // bottom, e.g. the 2x3 boxes of a 6x6 board.  The boxes must tile the board.
+    pub fn rectangular(box_rows: usize, box_cols: usize) -> Result<Regions<N>, SudokuError> {
+        if box_rows * box_cols != N {
+            return Err(SudokuError::BoxShape {
+                rows: box_rows,
+                cols: box_cols,
+                size: N,
+            });
+        }
+        let mut map = [[0; N]; N];
+        for (ridx, row) in map.iter_mut().enumerate() {
+            for (cidx, region) in row.iter_mut().enumerate() {
+                let (box_ridx, box_cidx) = Board::<N>::box_in(ridx, cidx, box_rows, box_cols);
+                *region = box_ridx * (N / box_cols) + box_cidx;
+            }
+        }
+        Regions::from_map(map)
+    }
+
+    // Build regions from the region id (0 to N-1) of each cell.  Every region must hold
+    // exactly N cells.
+    pub fn from_map(map: [[usize; N]; N]) -> Result<Regions<N>, SudokuError> {
+        let mut regions = Regions {
+            of: [[0; N]; N],
+            cells: [[(0, 0); N]; N],
+        };
+        let mut counts = [0; N];
+        for (ridx, row) in map.iter().enumerate() {
+            for (cidx, &region) in row.iter().enumerate() {
+                if region >= N {
+                    return Err(SudokuError::InvalidRegion {
+                        row: ridx,
+                        col: cidx,
+                        region,
+                    });
+                }
+                // Record at most N cells, but keep counting so that we can report the size.
+                if counts[region] < N {
+                    regions.cells[region][counts[region]] = (ridx as u8, cidx as u8);
+                }
+                counts[region] += 1;
+                regions.of[ridx][cidx] = region as u8;
+            }
+        }
+        for (region, &cells) in counts.iter().enumerate() {
+            if cells != N {
+                return Err(SudokuError::RegionSize {
+                    region,
+                    cells,
+                    wanted: N,
+                });
+            }
+        }
+        Ok(regions)
+    }
+
+    // The (rows, columns) of each region, when they are the boxes rectangular would build, e.g.
+    // to draw them.  Jigsaw regions have no such shape.
+    pub fn box_shape(&self) -> Option<(usize, usize)> {
+        let rows = (1..N)
+            .find(|&ridx| self.of(ridx, 0) != self.of(0, 0))
+            .unwrap_or(N);
+        let cols = (1..N)
+            .find(|&cidx| self.of(0, cidx) != self.of(0, 0))
+            .unwrap_or(N);
+        match Regions::rectangular(rows, cols) {
+            Ok(boxes) if boxes == *self => Some((rows, cols)),
+            _ => None,
+        }
+    }
+
+    // The region holding the given cell.
+    pub fn of(&self, ridx: usize, cidx: usize) -> usize {
+        self.of[ridx][cidx] as usize
+    }
+
+    // The (row, column) of the index-th cell of the given region.
+    pub fn cell(&self, region: usize, index: usize) -> (usize, usize) {
+        let (ridx, cidx) = self.cells[region][index];
+        (ridx as usize, cidx as usize)
+    }
+}
+
+impl<const N: usize> Board<N> {
+    // Create a Jigsaw board, where regions holds the region id (0 to N-1) of each cell and
+    // the regions replace the subsquares.  Fails if the regions aren't all of size N, or if
+    // the values break the rules.
+    pub fn with_regions(
+        values: [[usize; N]; N],
+        regions: [[usize; N]; N],
+    ) -> Result<Board<N>, SudokuError> {
+        Board::build(values, Regions::from_map(regions)?)
+    }
+
+    // Create a board whose boxes are box_rows by box_cols cells, rather than square, e.g. a
+    // 6x6 board with 2x3 boxes.  Fails if the boxes don't tile the board, or if the values
+    // break the rules.
+    pub fn with_boxes(
+        values: [[usize; N]; N],
+        box_rows: usize,
+        box_cols: usize,
+    ) -> Result<Board<N>, SudokuError> {
+        Board::build(values, Regions::rectangular(box_rows, box_cols)?)
+    }
+
+    // Parse a board written on a single line, as parse_line does, with boxes of box_rows by
+    // box_cols cells as with_boxes has them.
+    pub fn parse_line_with_boxes(
+        line: &str,
+        box_rows: usize,
+        box_cols: usize,
+    ) -> Result<Board<N>, SudokuError> {
+        Board::with_boxes(Self::line_values(line)?, box_rows, box_cols)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,170 @@
+use super::*;
+
+// A Jigsaw layout, giving the region of each cell.
+const JIGSAW: [[usize; 9]; 9] = [
+    [0, 0, 0, 1, 1, 2, 2, 2, 2],
+    [0, 0, 0, 1, 1, 1, 1, 2, 2],
+    [3, 0, 1, 1, 4, 4, 5, 2, 2],
+    [3, 0, 0, 1, 4, 4, 5, 2, 5],
+    [3, 3, 3, 3, 3, 4, 5, 5, 5],
+    [6, 3, 4, 4, 4, 4, 5, 5, 8],
+    [6, 3, 6, 7, 7, 7, 7, 5, 8],
+    [6, 6, 6, 6, 7, 8, 8, 8, 8],
+    [6, 6, 7, 7, 7, 7, 8, 8, 8],
+];
+
+// The solution to the Jigsaw puzzle below.
+const SOLUTION: [[usize; 9]; 9] = [
+    [7, 3, 2, 9, 5, 1, 8, 4, 6],
+    [9, 4, 5, 8, 3, 6, 1, 2, 7],
+    [1, 6, 7, 2, 8, 4, 5, 9, 3],
+    [2, 1, 8, 4, 6, 7, 3, 5, 9],
+    [3, 7, 4, 6, 9, 5, 2, 1, 8],
+    [6, 8, 9, 1, 2, 3, 4, 7, 5],
+    [8, 5, 1, 3, 7, 2, 9, 6, 4],
+    [5, 2, 3, 7, 4, 9, 6, 8, 1],
+    [4, 9, 6, 5, 1, 8, 7, 3, 2],
+];
+
+#[test]
+fn check_regions() {
+    // The solution breaks the standard rules, since its subsquares hold duplicates.
+    if let Ok(b) = Board::new(SOLUTION) {
+        panic!("wanted error due to duplicate in subsquare, got: {:#?}", b)
+    }
+
+    // But it is valid under the Jigsaw regions.
+    let board = Board::with_regions(SOLUTION, JIGSAW).expect("building jigsaw literal");
+    board.check().expect("Failed to validate board.");
+    assert!(board.is_complete());
+}
+
+#[test]
+fn check_bad_regions() {
+    // Region ids must be less than the size of the board.
+    let mut regions = JIGSAW;
+    regions[4][4] = 9;
+    assert_eq!(
+        Board::with_regions([[0; 9]; 9], regions).err(),
+        Some(SudokuError::InvalidRegion {
+            row: 4,
+            col: 4,
+            region: 9
+        })
+    );
+
+    // Every region must hold exactly one cell for each value.
+    let mut regions = JIGSAW;
+    regions[0][3] = 0;
+    assert_eq!(
+        Board::with_regions([[0; 9]; 9], regions).err(),
+        Some(SudokuError::RegionSize {
+            region: 0,
+            cells: 10,
+            wanted: 9
+        })
+    );
+}
+
+#[test]
+fn check_solve_regions() {
+    let mut input = Board::with_regions(
+        [
+            [0, 0, 0, 0, 0, 1, 8, 4, 0],
+            [0, 4, 0, 0, 0, 0, 0, 0, 0],
+            [0, 6, 0, 0, 0, 0, 0, 0, 3],
+            [0, 0, 0, 0, 0, 7, 0, 0, 0],
+            [0, 7, 0, 0, 0, 0, 2, 1, 0],
+            [0, 0, 9, 0, 0, 0, 0, 7, 0],
+            [0, 0, 0, 0, 0, 0, 0, 0, 0],
+            [5, 2, 0, 0, 4, 0, 0, 0, 0],
+            [4, 0, 0, 0, 0, 0, 0, 3, 0],
+        ],
+        JIGSAW,
+    )
+    .expect("building jigsaw literal");
+    assert!(input.has_unique_solution());
+
+    let solution = Board::with_regions(SOLUTION, JIGSAW).expect("building jigsaw literal");
+
+    input.solve().expect("error finding solution");
+    assert_eq!(input, solution);
+}
+
+#[test]
+fn check_default_boxes() {
+    // Boards whose size isn't a perfect square get boxes as close to square as the size
+    // allows, wider than they are tall.
+    assert_eq!(Board::<6>::box_of(3, 4), (1, 1));
+    assert_eq!(Board::<12>::box_of(5, 7), (1, 1));
+    assert!(Regions::<6>::standard() == Regions::<6>::rectangular(2, 3).expect("2x3 boxes"));
+    assert_eq!(Regions::<6>::standard().box_shape(), Some((2, 3)));
+    assert_eq!(Regions::<9>::standard().box_shape(), Some((3, 3)));
+    assert_eq!(
+        Regions::<9>::from_map(JIGSAW)
+            .expect("jigsaw regions")
+            .box_shape(),
+        None
+    );
+
+    assert_eq!(
+        Board::<6>::with_boxes([[0; 6]; 6], 3, 2).map(|empty| empty.regions.box_shape()),
+        Ok(Some((3, 2)))
+    );
+
+    // box_of only knows the default boxes, but box_at asks the board.
+    let tall = Board::<6>::with_boxes([[0; 6]; 6], 3, 2).expect("building 3x2 boxes");
+    assert_eq!(Board::<6>::box_of(2, 4), (1, 1));
+    assert_eq!(tall.box_at(2, 4), Some((0, 2)));
+    let default = Board::<6>::new([[0; 6]; 6]).expect("building board literal");
+    assert_eq!(default.box_at(2, 4), Some(Board::<6>::box_of(2, 4)));
+    let jigsaw = Board::with_regions([[0; 9]; 9], JIGSAW).expect("building jigsaw literal");
+    assert_eq!(jigsaw.box_at(4, 4), None);
+
+    // So a 6x6 board with 2x3 boxes needs nothing special.
+    let board = Board::<6>::parse_line("123456456123231564564231312645645312")
+        .expect("building board line");
+    board.check().expect("Failed to validate board.");
+    assert!(board.is_complete());
+}
+
+#[test]
+fn check_rectangular_boxes() {
+    // 2x3 boxes tile a 6x6 board, but 2x2 and 4x3 boxes don't.
+    assert!(Regions::<6>::rectangular(2, 3).is_ok());
+    assert_eq!(
+        Regions::<6>::rectangular(2, 2).err(),
+        Some(SudokuError::BoxShape {
+            rows: 2,
+            cols: 2,
+            size: 6
+        })
+    );
+    assert_eq!(
+        Regions::<6>::rectangular(4, 3).err(),
+        Some(SudokuError::BoxShape {
+            rows: 4,
+            cols: 3,
+            size: 6
+        })
+    );
+
+    // The transpose of the solution below has 3x2 boxes rather than 2x3 ones.
+    let line = "142536253614361425415263526341634152";
+    assert!(Board::<6>::parse_line_with_boxes(line, 3, 2).is_ok());
+    assert!(Board::<6>::parse_line_with_boxes(line, 2, 3).is_err());
+}
+
+#[test]
+fn check_solve_rectangular_boxes() {
+    let mut input = Board::<6>::parse_line_with_boxes("000000050103030004000200002600045000", 2, 3)
+        .expect("building board line");
+    assert!(input.has_unique_solution());
+
+    let solution = Board::<6>::parse_line_with_boxes("123456456123231564564231312645645312", 2, 3)
+        .expect("building board line");
+    solution.check().expect("Failed to validate board.");
+
+    input.solve().expect("error finding solution");
+    assert_eq!(input, solution);
+}
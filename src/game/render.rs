@@ -0,0 +1,163 @@
+use alloc::string::{String, ToString};
+use alloc::{format, vec};
+use core::fmt;
+
+use super::{Board, Cell};
+
+// The character used to display a value: the digits 1-9, followed by letters (A for 10 and
+// so on) on larger boards.
+pub(super) fn glyph(value: usize) -> char {
+    core::char::from_digit(value as u32, 36)
+        .expect("values fit in a single character")
+        .to_ascii_uppercase()
+}
+
+// Display the board as a grid with its subsquares marked out, and '.' for unsolved cells:
+//
+//   +-------+-------+-------+
+//   | 5 3 . | . 7 . | . . . |
+//   ...
+impl<const N: usize> fmt::Display for Board<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (rows, cols) = self.box_shape();
+        let border = format!("+{}+\n", vec!["-".repeat(cols * 2 + 1); N / cols].join("+"));
+        for (ridx, row) in self.cells.iter().enumerate() {
+            if ridx % rows == 0 {
+                f.write_str(&border)?;
+            }
+            for (cidx, cell) in row.iter().enumerate() {
+                if cidx % cols == 0 {
+                    f.write_str("| ")?;
+                }
+                match cell {
+                    Cell::Value(v) => write!(f, "{} ", glyph(*v))?,
+                    Cell::Options(_) => f.write_str(". ")?,
+                }
+            }
+            f.write_str("|\n")?;
+        }
+        f.write_str(&border)
+    }
+}
+
+impl<const N: usize> Board<N> {
+    // The (rows, columns) of the boxes to mark out: those of the regions when they are
+    // rectangular boxes, and otherwise the usual subsquares, e.g. on a Jigsaw board.
+    fn box_shape(&self) -> (usize, usize) {
+        self.regions
+            .box_shape()
+            .unwrap_or((Board::<N>::BOX_ROWS, Board::<N>::BOX_COLS))
+    }
+
+    // Render the board as the bordered grid that Display prints, e.g. for writing out puzzle
+    // sheets.  parse_grid reads it back.
+    pub fn to_ascii(&self) -> String {
+        self.to_string()
+    }
+
+    // Render the board with the candidates of each unsolved cell laid out in a mini-grid the
+    // shape of a box, with value v at the same position as subsquare v-1 of the board and '.'
+    // for values that are ruled out.  Solved cells show their value in the middle of the mini-grid:
+    //
+    //   +-------------+-------------+-------------+
+    //   |         12. | .2.     .2. | 1.. 12. .2. |
+    //   |  5   3  4.. | ..6  7  4.6 | 4.. 4.. 4.. |
+    //   |         ... | ...     .8. | .89 ..9 .8. |
+    //   |             |             |             |
+    //   ...
+    pub fn to_pencil_grid(&self) -> String {
+        let (rows, cols) = self.box_shape();
+        let border = format!(
+            "+{}+\n",
+            vec!["-".repeat(cols * (cols + 1) + 1); N / cols].join("+")
+        );
+        let spacer = format!(
+            "|{}|\n",
+            vec![" ".repeat(cols * (cols + 1) + 1); N / cols].join("|")
+        );
+        let mut out = String::new();
+        for ridx in 0..N {
+            if ridx % rows == 0 {
+                out.push_str(&border);
+            } else {
+                out.push_str(&spacer);
+            }
+            for line in 0..rows {
+                for cidx in 0..N {
+                    if cidx % cols == 0 {
+                        out.push_str("| ");
+                    }
+                    for pos in 0..cols {
+                        out.push(match self.cells[ridx][cidx] {
+                            Cell::Value(v) if line == rows / 2 && pos == cols / 2 => glyph(v),
+                            Cell::Value(_) => ' ',
+                            Cell::Options(_) => {
+                                let value = line * cols + pos + 1;
+                                if self.options(ridx, cidx).has(value) {
+                                    glyph(value)
+                                } else {
+                                    '.'
+                                }
+                            }
+                        });
+                    }
+                    out.push(' ');
+                }
+                out.push_str("|\n");
+            }
+        }
+        out.push_str(&border);
+        out
+    }
+
+    // Render the board on a single line, reading left to right and top to bottom, with '0'
+    // for unsolved cells.  This is the format that parse accepts, without the newlines.
+    pub fn to_line(&self) -> String {
+        self.to_line_with('0')
+    }
+
+    // Render the board on a single line as to_line does, with the given character for
+    // unsolved cells (e.g. '.', as many other tools expect).
+    pub fn to_line_with(&self, blank: char) -> String {
+        self.cells
+            .iter()
+            .flat_map(|row| row.iter())
+            .map(|cell| match cell {
+                Cell::Value(v) => glyph(*v),
+                Cell::Options(_) => blank,
+            })
+            .collect()
+    }
+
+    // Render the full state of the board, so that from_state can restore it mid-solve: a line
+    // per row of space-separated cells, each a given value (e.g. 5), a value placed since
+    // (e.g. +5), or the options of an unsolved cell (e.g. [1269]).
+    pub fn to_state(&self) -> String {
+        let mut out = String::new();
+        for (row, givens) in self.cells.iter().zip(self.givens.iter()) {
+            for (cidx, (cell, &given)) in row.iter().zip(givens.iter()).enumerate() {
+                if cidx > 0 {
+                    out.push(' ');
+                }
+                match cell {
+                    Cell::Value(v) => {
+                        if !given {
+                            out.push('+');
+                        }
+                        out.push(glyph(*v));
+                    }
+                    Cell::Options(opts) => {
+                        out.push('[');
+                        out.extend(opts.foreach().map(glyph));
+                        out.push(']');
+                    }
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
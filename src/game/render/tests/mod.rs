@@ -0,0 +1,207 @@
+use super::*;
+use crate::game::SudokuError;
+
+const PUZZLE: [[usize; 9]; 9] = [
+    [5, 3, 0, 0, 7, 0, 0, 0, 0],
+    [6, 0, 0, 1, 9, 5, 0, 0, 0],
+    [0, 9, 8, 0, 0, 0, 0, 6, 0],
+    [8, 0, 0, 0, 6, 0, 0, 0, 3],
+    [4, 0, 0, 8, 0, 3, 0, 0, 1],
+    [7, 0, 0, 0, 2, 0, 0, 0, 6],
+    [0, 6, 0, 0, 0, 0, 2, 8, 0],
+    [0, 0, 0, 4, 1, 9, 0, 0, 5],
+    [0, 0, 0, 0, 8, 0, 0, 7, 9],
+];
+
+#[test]
+fn check_display() {
+    let board = Board::new(PUZZLE).expect("building board literal");
+    assert_eq!(
+        board.to_string(),
+        "\
++-------+-------+-------+
+| 5 3 . | . 7 . | . . . |
+| 6 . . | 1 9 5 | . . . |
+| . 9 8 | . . . | . 6 . |
++-------+-------+-------+
+| 8 . . | . 6 . | . . 3 |
+| 4 . . | 8 . 3 | . . 1 |
+| 7 . . | . 2 . | . . 6 |
++-------+-------+-------+
+| . 6 . | . . . | 2 8 . |
+| . . . | 4 1 9 | . . 5 |
+| . . . | . 8 . | . 7 9 |
++-------+-------+-------+
+"
+    );
+}
+
+#[test]
+fn check_display_4x4() {
+    let board = Board::new([[1, 2, 0, 4], [0, 0, 1, 0], [0, 1, 0, 0], [4, 0, 0, 1]])
+        .expect("building board literal");
+    assert_eq!(
+        board.to_string(),
+        "\
++-----+-----+
+| 1 2 | . 4 |
+| . . | 1 . |
++-----+-----+
+| . 1 | . . |
+| 4 . | . 1 |
++-----+-----+
+"
+    );
+}
+
+#[test]
+fn check_display_6x6() {
+    // A 6x6 board gets 2x3 boxes by default.
+    let board = Board::<6>::parse_line("123456456123231564564231312645645312")
+        .expect("building board line");
+    assert_eq!(
+        board.to_string(),
+        "\
++-------+-------+
+| 1 2 3 | 4 5 6 |
+| 4 5 6 | 1 2 3 |
++-------+-------+
+| 2 3 1 | 5 6 4 |
+| 5 6 4 | 2 3 1 |
++-------+-------+
+| 3 1 2 | 6 4 5 |
+| 6 4 5 | 3 1 2 |
++-------+-------+
+"
+    );
+
+    // But any other boxes it is built with are marked out instead.
+    let board = Board::<6>::parse_line_with_boxes("142536253614361425415263526341634152", 3, 2)
+        .expect("building board line");
+    assert_eq!(
+        board.to_string(),
+        "\
++-----+-----+-----+
+| 1 4 | 2 5 | 3 6 |
+| 2 5 | 3 6 | 1 4 |
+| 3 6 | 1 4 | 2 5 |
++-----+-----+-----+
+| 4 1 | 5 2 | 6 3 |
+| 5 2 | 6 3 | 4 1 |
+| 6 3 | 4 1 | 5 2 |
++-----+-----+-----+
+"
+    );
+}
+
+#[test]
+fn check_to_line() {
+    let board = Board::new(PUZZLE).expect("building board literal");
+    let line = board.to_line();
+    assert_eq!(
+        line,
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079"
+    );
+
+    // Splitting the line into rows gives something parse understands.
+    let rows: Vec<&str> = (0..9).map(|idx| &line[idx * 9..idx * 9 + 9]).collect();
+    assert_eq!(Board::<9>::parse(rows.join("\n")), Ok(board));
+}
+
+#[test]
+fn check_to_line_with() {
+    let board = Board::new(PUZZLE).expect("building board literal");
+    assert_eq!(board.to_line_with('0'), board.to_line());
+
+    let line = board.to_line_with('.');
+    assert_eq!(
+        line,
+        "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79"
+    );
+    // Dots read back as blanks.
+    assert_eq!(Board::<9>::parse_grid(&line), Ok(board));
+}
+
+#[test]
+fn check_to_ascii() {
+    let mut board = Board::new([[0; 4]; 4]).expect("building board literal");
+    board.place(0, 0, 1).expect("placing value");
+    board.place(3, 2, 2).expect("placing value");
+    assert_eq!(
+        board.to_ascii(),
+        "\
++-----+-----+
+| 1 . | . . |
+| . . | . . |
++-----+-----+
+| . . | . . |
+| . . | 2 . |
++-----+-----+
+"
+    );
+    let parsed = Board::<4>::parse_grid(&board.to_ascii()).expect("reading grid back");
+    assert!(parsed.same_values(&board));
+}
+
+#[test]
+fn check_to_pencil_grid() {
+    // The candidates of each blank are placed like the subsquares: 1 and 2 on the top row of
+    // the cell, and 3 and 4 below them.
+    let board = Board::new([[1, 2, 0, 4], [0, 0, 1, 0], [0, 1, 0, 0], [4, 0, 0, 1]])
+        .expect("building board literal");
+    assert_eq!(
+        board.to_pencil_grid(),
+        "\
++-------+-------+
+|       | ..    |
+|  1  2 | 3.  4 |
+|       |       |
+| .. .. |    .2 |
+| 3. 34 |  1 3. |
++-------+-------+
+| .2    | .2 .2 |
+| 3.  1 | 34 3. |
+|       |       |
+|    .. | .2    |
+|  4 3. | 3.  1 |
++-------+-------+
+"
+    );
+}
+
+#[test]
+fn check_to_state() {
+    // Part way through solving, with a value placed and the candidates narrowed.
+    let mut board = Board::new(PUZZLE).expect("building board literal");
+    board.fill_candidates();
+    board.place(0, 2, 4).expect("placing candidate");
+    let state = board.to_state();
+    assert!(state.starts_with("5 3 +4 [26] 7 [268] [189] [129] [28]\n"));
+    assert_eq!(state.lines().count(), 9);
+
+    // It round-trips, candidates, givens, and all.
+    let restored = Board::<9>::from_state(&state).expect("restoring state");
+    assert_eq!(restored, board);
+    assert_eq!(restored.to_state(), state);
+    assert!(restored.is_given(0, 0));
+    assert!(!restored.is_given(0, 2));
+
+    // Bad tokens are rejected.
+    assert_eq!(
+        Board::<9>::from_state("5 3"),
+        Err(SudokuError::CellCount {
+            cells: 2,
+            wanted: 81
+        })
+    );
+    let bad = state.replacen("[26]", "[2A]", 1);
+    assert_eq!(
+        Board::<9>::from_state(&bad),
+        Err(SudokuError::InvalidCharacter('A'))
+    );
+    let bad = state.replacen("+4", "+44", 1);
+    assert_eq!(
+        Board::<9>::from_state(&bad),
+        Err(SudokuError::InvalidCharacter('4'))
+    );
+}
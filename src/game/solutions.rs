@@ -0,0 +1,92 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{Board, Cell};
+
+impl<const N: usize> Board<N> {
+    // Find every solution to the board.  An under-constrained board can have a great many, so
+    // this is best kept to boards that are nearly determined.
+    pub fn solve_all(&self) -> Vec<Board<N>> {
+        self.solutions().collect()
+    }
+
+    // Find the solutions to the board lazily, as the iterator is advanced, so that callers can
+    // stop early (e.g. with take) without searching for the rest.
+    pub fn solutions(&self) -> Solutions<N> {
+        Solutions {
+            pending: vec![*self],
+        }
+    }
+
+    // Find every solution to the board as solve_all does, searching the options of the first
+    // cell we have to guess at on separate threads.
+    #[cfg(feature = "std")]
+    pub fn solve_all_parallel(&self) -> Vec<Board<N>> {
+        let mut board = *self;
+        match board.solve_logically(Self::DEFAULT_STRATEGIES) {
+            Err(_) => return Vec::new(),    // The board is contradictory.
+            Ok(true) => return vec![board], // Logic alone solved the board.
+            Ok(false) => {}                 // We need to speculate.
+        }
+
+        let (ridx, cidx) = board.most_constrained();
+        let opts = match board.cells[ridx][cidx] {
+            Cell::Options(opts) => opts,
+            Cell::Value(_) => return Vec::new(),
+        };
+        std::thread::scope(|scope| {
+            let workers: Vec<_> = opts
+                .foreach()
+                .map(|value| {
+                    scope.spawn(move || {
+                        let mut speculator = board;
+                        match speculator.set(ridx, cidx, Cell::Value(value)) {
+                            Ok(()) => speculator.solve_all(),
+                            Err(_) => Vec::new(),
+                        }
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().expect("solver thread panicked"))
+                .collect()
+        })
+    }
+}
+
+// An iterator over the solutions to a board, found one at a time by backtracking over an
+// explicit stack of boards still to be explored.
+pub struct Solutions<const N: usize> {
+    pending: Vec<Board<N>>,
+}
+
+impl<const N: usize> Iterator for Solutions<N> {
+    type Item = Board<N>;
+
+    fn next(&mut self) -> Option<Board<N>> {
+        while let Some(mut board) = self.pending.pop() {
+            match board.solve_logically(Board::<N>::DEFAULT_STRATEGIES) {
+                Err(_) => continue,             // The board is contradictory.
+                Ok(true) => return Some(board), // Logic alone solved the board.
+                Ok(false) => {}                 // We need to speculate.
+            }
+
+            let (ridx, cidx) = board.most_constrained();
+            if let Cell::Options(opts) = board.cells[ridx][cidx] {
+                // Push in reverse, so that the smallest value is explored first.
+                let values: Vec<usize> = opts.foreach().collect();
+                for &value in values.iter().rev() {
+                    let mut speculator = board;
+                    if speculator.set(ridx, cidx, Cell::Value(value)).is_ok() {
+                        self.pending.push(speculator);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
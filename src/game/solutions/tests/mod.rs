@@ -0,0 +1,79 @@
+use super::*;
+
+// The solutions as lines, in a canonical order so they can be compared as sets.
+fn sorted(solutions: Vec<Board>) -> Vec<String> {
+    let mut lines: Vec<String> = solutions.iter().map(|b| b.to_line()).collect();
+    lines.sort();
+    lines
+}
+
+#[test]
+fn test_solve_all() {
+    // A puzzle with the clues on its top row removed, leaving it under-constrained.
+    let puzzle = Board::<9>::parse_line(
+        "000000000800000020070010500400005300010070006003200080060500009004000030000009700",
+    )
+    .expect("building board line");
+
+    let solutions = puzzle.solve_all();
+    assert_eq!(solutions.len(), 271);
+    for solution in solutions.iter() {
+        solution.check().expect("Failed to validate board.");
+        assert!(solution.is_complete());
+    }
+
+    let sequential = sorted(solutions);
+    let mut distinct = sequential.clone();
+    distinct.dedup();
+    assert_eq!(distinct, sequential);
+
+    assert_eq!(sorted(puzzle.solve_all_parallel()), sequential);
+}
+
+#[test]
+fn test_solve_all_trivial() {
+    // A puzzle that logic alone solves has just the one solution.
+    let puzzle = Board::<9>::parse_line(
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079",
+    )
+    .expect("building board line");
+    let mut solved = puzzle;
+    solved.solve().expect("error finding solution");
+    assert_eq!(puzzle.solve_all(), vec![solved]);
+    assert_eq!(puzzle.solve_all_parallel(), vec![solved]);
+
+    // A contradictory one has none.
+    let puzzle = Board::<9>::parse_line(
+        "123456780000000009000000000000000000000000000000000000000000000000000000000000000",
+    )
+    .expect("building board line");
+    assert!(puzzle.solve_all().is_empty());
+    assert!(puzzle.solve_all_parallel().is_empty());
+}
+
+#[test]
+fn test_solutions() {
+    // Solutions are found lazily, in the same order as solve_all.
+    let puzzle = Board::<9>::parse_line(
+        "000000000800000020070010500400005300010070006003200080060500009004000030000009700",
+    )
+    .expect("building board line");
+    let first: Vec<Board> = puzzle.solutions().take(3).collect();
+    assert_eq!(first, puzzle.solve_all()[..3]);
+    assert_eq!(puzzle.solutions().count(), 271);
+
+    // A uniquely-solvable board yields its solution and nothing more.
+    let puzzle = Board::<9>::parse_line(
+        "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+    )
+    .expect("building board line");
+    let mut solved = puzzle;
+    solved.solve().expect("error finding solution");
+    assert_eq!(
+        puzzle.solutions().take(1).collect::<Vec<Board>>(),
+        vec![solved]
+    );
+    let mut solutions = puzzle.solutions();
+    assert_eq!(solutions.next(), Some(solved));
+    assert_eq!(solutions.next(), None);
+}
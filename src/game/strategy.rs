@@ -0,0 +1,203 @@
+use core::fmt;
+
+use super::bitset::BitSet;
+use super::{Board, Cell, SudokuError};
+
+// A Strategy is a technique for making logical progress on a board.  Apply returns whether
+// the board was changed, which the solver uses to decide whether it has stalled.
+// Technique names the kind of deduction the strategy makes, for reporting.
+pub trait Strategy<const N: usize = 9> {
+    fn apply(&self, board: &mut Board<N>) -> Result<bool, SudokuError>;
+    fn technique(&self) -> Technique;
+}
+
+// The techniques the solver knows how to apply, ordered from the simplest.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Technique {
+    NakedSingle,      // The only remaining option for a cell.
+    HiddenSingle,     // The only cell in a row, column, or region that can hold a value.
+    BoxLineReduction, // A value confined to where a line crosses a region.
+    CageSum,          // A value that can't help a Killer cage reach its sum.
+    Guess,            // Speculating a value when logic has stalled.
+    Retract,          // Undoing a guess that led to failure, along with what followed it.
+}
+
+// A human-readable label, e.g. for explaining a hint or a solving log.  These are stable, so
+// callers can match on them.
+impl fmt::Display for Technique {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Technique::NakedSingle => "naked single",
+            Technique::HiddenSingle => "hidden single",
+            Technique::BoxLineReduction => "box-line reduction",
+            Technique::CageSum => "cage sum",
+            Technique::Guess => "guess",
+            Technique::Retract => "retract",
+        })
+    }
+}
+
+// NakedSingles narrows the options of every cell to the values that aren't already placed in
+// its row, column, or region, and places any value that is the only remaining option.
+pub struct NakedSingles;
+
+impl<const N: usize> Strategy<N> for NakedSingles {
+    fn apply(&self, board: &mut Board<N>) -> Result<bool, SudokuError> {
+        let mut changed = false;
+        for ridx in 0..N {
+            for cidx in 0..N {
+                if let Cell::Options(og_opts) = board.cells[ridx][cidx] {
+                    let opts = board.options(ridx, cidx);
+                    if opts.empty() {
+                        // If there are no options, then something went wrong.
+                        return Err(SudokuError::NoOptions {
+                            row: ridx,
+                            col: cidx,
+                        });
+                    } else if let Some(value) = opts.singleton() {
+                        // If it's a power of two, then there's only one option.
+                        board.set(ridx, cidx, Cell::Value(value))?;
+                        changed = true;
+                    } else {
+                        board.set(ridx, cidx, Cell::Options(opts))?;
+                        if og_opts != opts {
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+        board.check()?;
+        Ok(changed)
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::NakedSingle
+    }
+}
+
+// HiddenSingles places a value in a cell when that cell is the only one in its row, column,
+// or region that could hold it.
+pub struct HiddenSingles;
+
+impl<const N: usize> Strategy<N> for HiddenSingles {
+    fn apply(&self, board: &mut Board<N>) -> Result<bool, SudokuError> {
+        let mut changed = false;
+        for ridx in 0..N {
+            for cidx in 0..N {
+                if let Some(value) = board.hidden_single_value(ridx, cidx) {
+                    board.set(ridx, cidx, Cell::Value(value))?;
+                    changed = true;
+                }
+            }
+        }
+        board.check()?;
+        Ok(changed)
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::HiddenSingle
+    }
+}
+
+impl<const N: usize> Board<N> {
+    // The value, if any, that the given cell is the only one in its row, column, or region
+    // that could hold.  The options of each cell are narrowed by the values placed so far,
+    // since those held in the cells may be stale.
+    pub(super) fn hidden_single_value(&self, ridx: usize, cidx: usize) -> Option<usize> {
+        // Count the given cells that have value as an option.
+        let frequency = |cells: &mut dyn Iterator<Item = (usize, usize)>, value: usize| {
+            cells
+                .filter(|&(ridx, cidx)| self.options(ridx, cidx).has(value))
+                .count()
+        };
+
+        let region = self.regions.of(ridx, cidx);
+        self.options(ridx, cidx).foreach().find(|&value| {
+            frequency(&mut (0..N).map(|idx| (ridx, idx)), value) == 1
+                || frequency(&mut (0..N).map(|idx| (idx, cidx)), value) == 1
+                || frequency(&mut (0..N).map(|idx| self.regions.cell(region, idx)), value) == 1
+        })
+    }
+}
+
+// BoxLineReduction eliminates options when a value is confined to where a row or column
+// crosses a region: it can then be removed from the rest of that row/column or region.
+pub struct BoxLineReduction;
+
+impl<const N: usize> Strategy<N> for BoxLineReduction {
+    fn apply(&self, board: &mut Board<N>) -> Result<bool, SudokuError> {
+        let rows = reduce_lines(board, false);
+        let cols = reduce_lines(board, true);
+        Ok(rows || cols)
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::BoxLineReduction
+    }
+}
+
+// Perform box-line reduction over the rows of the board (or the columns, when transposed).
+// If a value's options within a line are confined to one region, it is removed from the rest
+// of the region; if its options within a region are confined to one line, it is removed from
+// the rest of the line.
+fn reduce_lines<const N: usize>(board: &mut Board<N>, transpose: bool) -> bool {
+    let at = |line: usize, offset: usize| {
+        if transpose {
+            (offset, line)
+        } else {
+            (line, offset)
+        }
+    };
+    let line_of = |ridx: usize, cidx: usize| if transpose { cidx } else { ridx };
+
+    let mut changed = false;
+    for line in 0..N {
+        for value in 1..=N {
+            // The regions crossed by the line that could hold value.
+            let regions = (0..N)
+                .map(|offset| at(line, offset))
+                .filter(|&(ridx, cidx)| board.options(ridx, cidx).has(value))
+                .fold(BitSet::new(&[]), |regions, (ridx, cidx)| {
+                    regions.set(board.regions.of(ridx, cidx))
+                });
+            if let Some(region) = regions.singleton() {
+                // Only this region of the line can hold value, so clear it from the rest of the
+                // region.
+                for index in 0..N {
+                    let (ridx, cidx) = board.regions.cell(region, index);
+                    if line_of(ridx, cidx) != line && board.eliminate(ridx, cidx, value) {
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+
+    for region in 0..N {
+        for value in 1..=N {
+            // The lines crossing the region that could hold value.
+            let lines = (0..N)
+                .map(|index| board.regions.cell(region, index))
+                .filter(|&(ridx, cidx)| board.options(ridx, cidx).has(value))
+                .fold(BitSet::new(&[]), |lines, (ridx, cidx)| {
+                    lines.set(line_of(ridx, cidx))
+                });
+            if let Some(line) = lines.singleton() {
+                // Only this line of the region can hold value, so clear it from the rest of the
+                // line.
+                for offset in 0..N {
+                    let (ridx, cidx) = at(line, offset);
+                    if board.regions.of(ridx, cidx) != region && board.eliminate(ridx, cidx, value)
+                    {
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+    changed
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
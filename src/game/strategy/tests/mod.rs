@@ -0,0 +1,75 @@
+use super::*;
+use crate::game::fixtures::{harder, stall, Counter};
+
+#[test]
+fn test_default_strategies() {
+    // The default strategies solve this without guessing.
+    let mut board = harder();
+    assert_eq!(stall(&mut board, Board::DEFAULT_STRATEGIES), 0);
+}
+
+#[test]
+fn test_naked_singles_only() {
+    let solution = {
+        let mut b = harder();
+        b.solve().expect("error finding solution");
+        b
+    };
+
+    // With only naked singles, the logic stalls and we must guess.
+    let mut board = harder();
+    assert_ne!(stall(&mut board, &[&NakedSingles]), 0);
+
+    // Guessing still gets us to the same solution.
+    let mut board = harder();
+    board
+        .solve_with(&[&NakedSingles])
+        .expect("error finding solution");
+    assert_eq!(board, solution);
+}
+
+#[test]
+fn test_custom_strategy() {
+    let counter = Counter(std::cell::Cell::new(0));
+    let mut board = harder();
+    board
+        .solve_with(&[&NakedSingles, &HiddenSingles, &counter])
+        .expect("error finding solution");
+    assert_ne!(counter.0.get(), 0);
+}
+
+#[test]
+fn test_hidden_singles_stale_options() {
+    // Nothing has narrowed the options held in the cells yet, so both of the blanks in the
+    // top row still list 8, even though the 8 in the last column rules out the top-right one.
+    let mut board = Board::new([
+        [1, 2, 3, 4, 5, 6, 7, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 8],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ])
+    .expect("building board literal");
+
+    // Hidden singles should see past the stale options, and place the 8 in the only cell of
+    // the row that can hold it.
+    assert!(HiddenSingles
+        .apply(&mut board)
+        .expect("applying hidden singles"));
+    assert_eq!(board.cells[0][7], Cell::Value(8));
+}
+
+#[test]
+fn test_technique_labels() {
+    assert_eq!(Technique::NakedSingle.to_string(), "naked single");
+    assert_eq!(Technique::HiddenSingle.to_string(), "hidden single");
+    assert_eq!(
+        Technique::BoxLineReduction.to_string(),
+        "box-line reduction"
+    );
+    assert_eq!(Technique::Guess.to_string(), "guess");
+}
@@ -1,3 +1,11 @@
+// The original tests predate clippy's stricter lints, and are kept as they were written.
+#![allow(
+    clippy::assign_op_pattern,
+    clippy::bool_assert_comparison,
+    clippy::single_match,
+    clippy::unnecessary_cast
+)]
+
 use super::*;
 
 fn assert_cell(lhs: Cell, rhs: Cell) {
@@ -103,19 +111,505 @@ const BAD_BOARD_ROW_TOO_SHORT: &str = "\
 
 #[test]
 fn check_parse() {
-    Board::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
-    Board::parse(GOOD_BOARD_SPACES.to_string()).expect("good board spaces");
-    if let Ok(b) = Board::parse(BAD_BOARD_CHAR.to_string()) {
+    Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    Board::<9>::parse(GOOD_BOARD_SPACES.to_string()).expect("good board spaces");
+    if let Ok(b) = Board::<9>::parse(BAD_BOARD_CHAR.to_string()) {
         panic!("wanted error due to bad char: 'a', got: {:#?}", b)
     }
-    if let Ok(b) = Board::parse(BAD_BOARD_TOO_FEW_ROWS.to_string()) {
+    if let Ok(b) = Board::<9>::parse(BAD_BOARD_TOO_FEW_ROWS.to_string()) {
         panic!("wanted error due to too few rows, got: {:#?}", b)
     }
-    if let Ok(b) = Board::parse(BAD_BOARD_ROW_TOO_SHORT.to_string()) {
+    if let Ok(b) = Board::<9>::parse(BAD_BOARD_ROW_TOO_SHORT.to_string()) {
         panic!("wanted error due to too few rows, got: {:#?}", b)
     }
 }
 
+#[test]
+fn check_from_reader() {
+    let board = Board::<9>::from_reader(GOOD_BOARD_ZEROS.as_bytes()).expect("good board zeros");
+    assert_eq!(Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()), Ok(board));
+
+    assert_eq!(
+        Board::<9>::from_reader(BAD_BOARD_TOO_FEW_ROWS.as_bytes()),
+        Board::<9>::parse(BAD_BOARD_TOO_FEW_ROWS.to_string())
+    );
+    assert_eq!(
+        Board::<9>::from_reader(&[0xff, 0xfe][..]),
+        Err(SudokuError::Read(std::io::ErrorKind::InvalidData))
+    );
+}
+
+#[test]
+fn check_parse_annotated() {
+    let file = "\
+# From the puzzles in check_solve_hardest.
+# Rated very hard.
+
+005300000
+800000020
+070010500
+
+400005300
+010070006
+003200080
+# The last band.
+060500009
+004000030
+000009700
+
+";
+    let board = Board::<9>::parse_annotated(file).expect("annotated board");
+    assert_eq!(Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()), Ok(board));
+
+    // Rows of spaces are blanks, not blank lines.
+    let spaces = GOOD_BOARD_ZEROS.replace("000009700", "         ");
+    let board = Board::<9>::parse_annotated(&spaces).expect("board with a blank row");
+    assert_eq!(board.count_filled(), 21);
+
+    // The comments don't make up for missing rows.
+    assert_eq!(
+        Board::<9>::parse_annotated("# Just the top band.\n005300000\n800000020\n070010500\n"),
+        Err(SudokuError::RowCount { rows: 3, wanted: 9 })
+    );
+}
+
+#[test]
+fn check_parse_letters() {
+    // 16x16 boards use letters after 9, in either case.
+    let line = format!("123456789aBcDeFg{}", "0".repeat(240));
+    let board = Board::<16>::parse_line(&line).expect("building 16x16 line");
+    for (cidx, value) in (1..=16).enumerate() {
+        assert_eq!(board.cells[0][cidx], Cell::Value(value));
+    }
+    assert_eq!(
+        board.to_line(),
+        format!("123456789ABCDEFG{}", "0".repeat(240))
+    );
+
+    // But only up to the size of the board.
+    let line = format!("H{}", "0".repeat(255));
+    assert_eq!(
+        Board::<16>::parse_line(&line),
+        Err(SudokuError::InvalidCharacter('H'))
+    );
+    let line = format!("A{}", "0".repeat(80));
+    assert_eq!(
+        Board::<9>::parse_line(&line),
+        Err(SudokuError::InvalidCharacter('A'))
+    );
+}
+
+#[test]
+fn check_parse_surrounding_blank_lines() {
+    let want = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    let padded = format!("\n\n{}\n\n", GOOD_BOARD_ZEROS);
+    assert_eq!(Board::<9>::parse(padded), Ok(want));
+
+    // Whitespace on an otherwise blank line is called out, rather than the row count.
+    let padded = format!("  \n{}", GOOD_BOARD_ZEROS);
+    assert_eq!(Board::<9>::parse(padded), Err(SudokuError::BlankLine(0)));
+    let gap = GOOD_BOARD_ZEROS.replacen("\n", "\n\n", 1);
+    assert_eq!(Board::<9>::parse(gap), Err(SudokuError::BlankLine(1)));
+}
+
+#[test]
+fn check_parse_lenient() {
+    let full = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    assert_eq!(Board::<9>::parse_lenient(GOOD_BOARD_ZEROS), Ok(full));
+
+    // The last two rows are missing, so they are left blank.
+    let seven_rows = "\
+005300000
+800000020
+070010500
+400005300
+010070006
+003200080
+060500009";
+    let board = Board::<9>::parse_lenient(seven_rows).expect("seven rows");
+    assert_eq!(board.count_filled(), 23 - 4);
+    assert_eq!(board.cells[..7], full.cells[..7]);
+    assert_eq!(board.cells[6][8], Cell::Value(9));
+    assert!(matches!(board.cells[7][7], Cell::Options(_)));
+
+    // Trailing blanks may be left off of each row.
+    let short_rows = "\
+0053
+80000002
+07001050
+4000053
+010070006
+0032000800
+06050000900
+00400003
+000009700";
+    if let Ok(b) = Board::<9>::parse_lenient(short_rows) {
+        panic!("wanted error due to long row, got: {:#?}", b)
+    }
+    let short_rows = short_rows
+        .replace("0032000800", "00320008")
+        .replace("06050000900", "060500009");
+    let board = Board::<9>::parse_lenient(&short_rows).expect("short rows");
+    assert_eq!(board, full);
+
+    // Strict parsing still wants every cell.
+    if let Ok(b) = Board::<9>::parse(short_rows) {
+        panic!("wanted error due to short rows, got: {:#?}", b)
+    }
+    if let Ok(b) = Board::<9>::parse_lenient(&format!("{}\n000000000", GOOD_BOARD_ZEROS)) {
+        panic!("wanted error due to too many rows, got: {:#?}", b)
+    }
+}
+
+#[test]
+fn check_parse_line() {
+    let board = Board::<9>::parse_line(
+        "005300000800000020070010500400005300010070006003200080060500009004000030000009700",
+    )
+    .expect("good board line");
+    assert_eq!(Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()), Ok(board));
+
+    assert_eq!(
+        Board::<9>::parse_line("0053000008"),
+        Err(SudokuError::LineLength {
+            length: 10,
+            wanted: 81
+        })
+    );
+    if let Ok(b) = Board::<9>::parse_line(&BAD_BOARD_CHAR.replace("\n", "")) {
+        panic!("wanted error due to bad char: 'a', got: {:#?}", b)
+    }
+}
+
+#[test]
+fn check_parse_grid() {
+    let grid = "\
++-------+-------+-------+
+| . . 5 | 3 . . | . . . |
+| 8 . . | . . . | . 2 . |
+| . 7 . | . 1 . | 5 . . |
++-------+-------+-------+
+| 4 . . | . . 5 | 3 . . |
+| . 1 . | . 7 . | . . 6 |
+| . . 3 | 2 . . | . 8 . |
++-------+-------+-------+
+| . 6 . | 5 . . | . . 9 |
+| . . 4 | . . . | . 3 . |
+| . . . | . . 9 | 7 . . |
++-------+-------+-------+
+";
+    let board = Board::<9>::parse_grid(grid).expect("good board grid");
+    assert_eq!(Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()), Ok(board));
+    // What Display prints can be read back.
+    assert_eq!(board.to_string(), grid);
+
+    // The separators are optional, and zeros work as blanks too.
+    assert_eq!(Board::<9>::parse_grid(GOOD_BOARD_ZEROS), Ok(board));
+
+    // Dropping a cell leaves too few.
+    assert_eq!(
+        Board::<9>::parse_grid(&grid.replacen(". ", "", 1)),
+        Err(SudokuError::CellCount {
+            cells: 80,
+            wanted: 81
+        })
+    );
+    assert_eq!(
+        Board::<9>::parse_grid(&grid.replacen(".", "a", 1)),
+        Err(SudokuError::InvalidCharacter('a'))
+    );
+}
+
+#[test]
+fn check_classify_many() {
+    let solved = solve_str(GOOD_BOARD_ZEROS).expect("error finding solution");
+    let duplicate = GOOD_BOARD_ZEROS.replacen('0', "5", 1);
+    let inputs = [
+        GOOD_BOARD_ZEROS,
+        "0053",
+        &solved,
+        &duplicate,
+        &"0".repeat(81),
+        "x",
+    ];
+    assert_eq!(
+        classify_many(inputs.iter().copied()),
+        vec![
+            (0, Ok(BoardStatus::Partial)),
+            (
+                1,
+                Err(SudokuError::CellCount {
+                    cells: 4,
+                    wanted: 81
+                })
+            ),
+            (2, Ok(BoardStatus::Complete)),
+            (3, Ok(BoardStatus::Invalid)),
+            (4, Ok(BoardStatus::Empty)),
+            (5, Err(SudokuError::InvalidCharacter('x'))),
+        ]
+    );
+}
+
+#[test]
+fn check_solve_str() {
+    assert_eq!(
+        solve_str(GOOD_BOARD_ZEROS),
+        Ok(
+            "145327698839654127672918543496185372218473956753296481367542819984761235521839764"
+                .to_string()
+        )
+    );
+    // The same puzzle on a single line.
+    assert_eq!(
+        solve_str(&GOOD_BOARD_ZEROS.replace("\n", "")),
+        solve_str(GOOD_BOARD_ZEROS)
+    );
+
+    assert_eq!(
+        solve_str("0053"),
+        Err(SudokuError::CellCount {
+            cells: 4,
+            wanted: 81
+        })
+    );
+    assert_eq!(
+        solve_str(&GOOD_BOARD_ZEROS.replacen("0", "5", 1)),
+        Err(SudokuError::Clash {
+            row: 0,
+            col: 2,
+            value: 5,
+            peer_row: 0,
+            peer_col: 0
+        })
+    );
+}
+
+#[test]
+fn check_new_duplicates() {
+    // The rules are checked once the values are all in place, which still catches a duplicate
+    // in any unit, naming the later cell and the peer it clashes with.
+    for (ridx, cidx) in [(0, 8), (8, 0), (2, 2)] {
+        let mut values = [[0; 9]; 9];
+        values[0][0] = 3;
+        values[ridx][cidx] = 3;
+        assert_eq!(
+            Board::new(values).err(),
+            Some(SudokuError::Clash {
+                row: ridx,
+                col: cidx,
+                value: 3,
+                peer_row: 0,
+                peer_col: 0
+            })
+        );
+    }
+    // Cells that aren't peers can hold the same value.
+    let mut values = [[0; 9]; 9];
+    values[0][0] = 3;
+    values[1][3] = 3;
+    values[4][4] = 3;
+    values[4][7] = 3;
+    assert_eq!(
+        Board::new(values).err(),
+        Some(SudokuError::Clash {
+            row: 4,
+            col: 7,
+            value: 3,
+            peer_row: 4,
+            peer_col: 4
+        })
+    );
+    // Values out of range are still reported ahead of the rules.
+    let mut values = [[0; 9]; 9];
+    values[0][0] = 3;
+    values[0][1] = 3;
+    values[8][8] = 10;
+    assert_eq!(
+        Board::new(values).err(),
+        Some(SudokuError::InvalidValue {
+            row: 8,
+            col: 8,
+            value: 10
+        })
+    );
+}
+
+#[test]
+fn check_from_rows_iter() {
+    let rows: Vec<Vec<usize>> = vec![vec![0; 9]; 9];
+    let board = Board::<9>::from_rows_iter(rows.clone()).expect("building from rows");
+    assert_eq!(board.status(), BoardStatus::Empty);
+
+    // Any nested iterable will do, e.g. the values decoded from a string.
+    let lines = GOOD_BOARD_ZEROS
+        .lines()
+        .map(|line| line.chars().map(|c| c as usize - '0' as usize));
+    assert_eq!(
+        Board::<9>::from_rows_iter(lines),
+        Board::<9>::parse(GOOD_BOARD_ZEROS.to_string())
+    );
+
+    // The shape is checked as the rows are consumed.
+    assert_eq!(
+        Board::<9>::from_rows_iter(vec![vec![0; 9]; 10]),
+        Err(SudokuError::RowCount {
+            rows: 10,
+            wanted: 9
+        })
+    );
+    assert_eq!(
+        Board::<9>::from_rows_iter(vec![vec![0; 10]; 9]),
+        Err(SudokuError::ColumnCount {
+            row: 0,
+            columns: 10,
+            wanted: 9
+        })
+    );
+    assert_eq!(
+        Board::<9>::from_rows_iter(rows.into_iter().take(3)),
+        Err(SudokuError::RowCount { rows: 3, wanted: 9 })
+    );
+}
+
+#[test]
+fn check_from_vec() {
+    let rows: Vec<Vec<usize>> = GOOD_BOARD_ZEROS
+        .lines()
+        .map(|line| line.chars().map(|c| c as usize - '0' as usize).collect())
+        .collect();
+    assert_eq!(
+        Board::<9>::from_vec(&rows),
+        Board::<9>::parse(GOOD_BOARD_ZEROS.to_string())
+    );
+
+    let mut ragged = rows.clone();
+    ragged[3].pop();
+    assert_eq!(
+        Board::<9>::from_vec(&ragged),
+        Err(SudokuError::ColumnCount {
+            row: 3,
+            columns: 8,
+            wanted: 9
+        })
+    );
+    assert_eq!(
+        Board::<9>::from_vec(&rows[1..]),
+        Err(SudokuError::RowCount { rows: 8, wanted: 9 })
+    );
+
+    // The range is checked before the rules, so we hear about this rather than the 5 that
+    // would be a duplicate in the top row.
+    let mut out_of_range = rows;
+    out_of_range[0][0] = 5;
+    out_of_range[6][4] = 10;
+    assert_eq!(
+        Board::<9>::from_vec(&out_of_range),
+        Err(SudokuError::InvalidValue {
+            row: 6,
+            col: 4,
+            value: 10
+        })
+    );
+}
+
+#[test]
+fn check_from_slice() {
+    let values: Vec<usize> = GOOD_BOARD_ZEROS
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c as usize - '0' as usize)
+        .collect();
+    assert_eq!(
+        Board::<9>::from_slice(&values),
+        Board::<9>::parse(GOOD_BOARD_ZEROS.to_string())
+    );
+
+    assert_eq!(
+        Board::<9>::from_slice(&values[1..]),
+        Err(SudokuError::CellCount {
+            cells: 80,
+            wanted: 81
+        })
+    );
+    assert_eq!(
+        Board::<9>::from_slice(&[0; 82]),
+        Err(SudokuError::CellCount {
+            cells: 82,
+            wanted: 81
+        })
+    );
+
+    // Bad values are reported by their index in the slice.
+    let mut out_of_range = values;
+    out_of_range[58] = 10;
+    assert_eq!(
+        Board::<9>::from_slice(&out_of_range),
+        Err(SudokuError::InvalidEntry {
+            index: 58,
+            value: 10
+        })
+    );
+}
+
+#[test]
+fn check_box_of() {
+    assert_eq!(Board::<9>::box_of(0, 0), (0, 0));
+    assert_eq!(Board::<9>::box_of(2, 2), (0, 0));
+    assert_eq!(Board::<9>::box_of(0, 3), (0, 1));
+    assert_eq!(Board::<9>::box_of(4, 3), (1, 1));
+    assert_eq!(Board::<9>::box_of(5, 8), (1, 2));
+    assert_eq!(Board::<9>::box_of(6, 1), (2, 0));
+    assert_eq!(Board::<9>::box_of(8, 8), (2, 2));
+
+    // Subsquares are 2x2 on a 4x4 board, and 4x4 on a 16x16 one.
+    assert_eq!(Board::<4>::box_of(1, 2), (0, 1));
+    assert_eq!(Board::<16>::box_of(7, 12), (1, 3));
+
+    // The standard regions are numbered by subsquare.
+    let board = Board::<9>::new([[0; 9]; 9]).expect("building board literal");
+    for ridx in 0..9 {
+        for cidx in 0..9 {
+            let (ss_ridx, ss_cidx) = Board::<9>::box_of(ridx, cidx);
+            assert_eq!(board.regions.of(ridx, cidx), ss_ridx * 3 + ss_cidx);
+        }
+    }
+}
+
+#[test]
+fn check_try_from() {
+    let board = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+
+    let mut values = [[0; 9]; 9];
+    for (ridx, line) in GOOD_BOARD_ZEROS.lines().enumerate() {
+        for (cidx, c) in line.chars().enumerate() {
+            values[ridx][cidx] = c as usize - '0' as usize;
+        }
+    }
+    assert_eq!(Board::try_from(values), Ok(board));
+    values[0][0] = 5;
+    assert_eq!(
+        Board::try_from(values),
+        Err(SudokuError::Clash {
+            row: 0,
+            col: 2,
+            value: 5,
+            peer_row: 0,
+            peer_col: 0
+        })
+    );
+
+    assert_eq!(Board::<9>::try_from(GOOD_BOARD_ZEROS), Ok(board));
+    assert_eq!(
+        Board::<9>::try_from(GOOD_BOARD_ZEROS.replace("\n", "").as_str()),
+        Ok(board)
+    );
+    assert_eq!(
+        Board::<9>::try_from(BAD_BOARD_CHAR),
+        Err(SudokuError::InvalidCharacter('a'))
+    );
+}
+
 #[test]
 fn check_equality() {
     // Programatically define a board with the diagonal
@@ -152,6 +646,555 @@ fn check_equality() {
     assert_ne!(b, as_literal);
 }
 
+#[test]
+fn check_same_values() {
+    let input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    let mut filled = input;
+    filled.fill_candidates();
+
+    // Narrowing the candidates changes the cells, but not the values.
+    assert_ne!(filled, input);
+    assert!(filled.same_values(&input));
+    assert!(input.same_values(&filled));
+
+    // Placing a value changes both.
+    let mut placed = input;
+    placed.place(4, 3, 8).expect("placing candidate");
+    assert_ne!(placed, input);
+    assert!(!placed.same_values(&input));
+    assert!(!input.same_values(&placed));
+
+    // Which values were givens doesn't matter to either.
+    let mut solved = input;
+    solved.solve().expect("error finding solution");
+    let solution = Board::<9>::parse_line(&solved.to_line()).expect("building board line");
+    assert_eq!(solved, solution);
+    assert!(solved.same_values(&solution));
+}
+
+#[test]
+fn check_values_eq() {
+    let input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    let mut solved = input;
+    solved.solve().expect("error finding solution");
+
+    // The solution agrees with the puzzle on every value the puzzle holds, though neither ==
+    // nor same_values says so.
+    assert_ne!(solved, input);
+    assert!(!solved.same_values(&input));
+    assert!(solved.values_eq(&input));
+    assert!(input.values_eq(&solved));
+
+    // As does a board part way there, whatever its candidates.
+    let mut partial = input;
+    partial.solve_logical().expect("error solving");
+    assert!(!partial.is_complete());
+    assert_ne!(partial, solved);
+    assert!(partial.values_eq(&solved));
+    assert!(partial.values_eq(&input));
+
+    // But a value that differs from the solution doesn't agree.
+    let mut wrong = input;
+    wrong.place(0, 0, 2).expect("placing candidate");
+    assert_ne!(solved.get(0, 0), Ok(Cell::Value(2)));
+    assert!(wrong.values_eq(&input));
+    assert!(!wrong.values_eq(&solved));
+}
+
+#[test]
+fn check_hash() {
+    let input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    let line = Board::<9>::parse_line(&GOOD_BOARD_ZEROS.replace("\n", "")).expect("board line");
+    let mut solved = input;
+    solved.solve().expect("error finding solution");
+
+    // Equal boards hash alike, however they were built, so they are deduplicated.
+    let boards: std::collections::HashSet<Board> =
+        [input, line, solved, input].iter().copied().collect();
+    assert_eq!(boards.len(), 2);
+    assert!(boards.contains(&line));
+    assert!(boards.contains(&solved));
+}
+
+#[test]
+fn check_diff() {
+    let mut solution = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    solution.solve().expect("error finding solution");
+    assert!(solution.diff(&solution).is_empty());
+
+    // Swap two values in the bottom row, as a player might by mistake.
+    let mut attempt = solution;
+    let (left, right) = (attempt.cells[8][0], attempt.cells[8][1]);
+    attempt.put(8, 0, right);
+    attempt.put(8, 1, left);
+    assert_eq!(
+        attempt.diff(&solution),
+        vec![(8, 0, right, left), (8, 1, left, right)]
+    );
+}
+
+#[test]
+fn check_status() {
+    let empty = Board::<9>::new([[0; 9]; 9]).expect("building board literal");
+    assert_eq!(empty.status(), BoardStatus::Empty);
+
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    assert_eq!(input.status(), BoardStatus::Partial);
+
+    let mut invalid = input;
+    invalid.put(0, 0, Cell::Value(5));
+    assert_eq!(invalid.status(), BoardStatus::Invalid);
+
+    input.solve().expect("error finding solution");
+    assert_eq!(input.status(), BoardStatus::Complete);
+    // A board that arrives complete says so.
+    let parsed = Board::<9>::parse_line(&input.to_line()).expect("building board line");
+    assert_eq!(parsed.status(), BoardStatus::Complete);
+}
+
+#[test]
+fn check_total_candidates() {
+    let empty = Board::<9>::new([[0; 9]; 9]).expect("building board literal");
+    assert_eq!(empty.total_candidates(), 81 * 9);
+
+    // Placed values rule out options, whether or not the candidates have been filled in.
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    let open = input.total_candidates();
+    assert!(open < (81 - 23) * 9);
+    input.fill_candidates();
+    assert_eq!(input.total_candidates(), open);
+
+    // Progress shrinks it, down to nothing once solved.
+    input.solve_one().expect("error solving");
+    assert!(input.total_candidates() < open);
+    input.solve().expect("error finding solution");
+    assert_eq!(input.total_candidates(), 0);
+}
+
+#[test]
+fn check_solve_logical() {
+    // Logic alone solves an easy puzzle.
+    let mut input = Board::<9>::parse_line(
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079",
+    )
+    .expect("building board line");
+    input.solve_logical().expect("error solving");
+    assert!(input.is_complete());
+
+    // But "Everest" needs guessing, so some cells are left as options.
+    let mut input = Board::<9>::parse_line(
+        "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+    )
+    .expect("building board line");
+    input.solve_logical().expect("error solving");
+    assert!(!input.is_complete());
+    assert!(input
+        .cells
+        .iter()
+        .flatten()
+        .any(|cell| matches!(cell, Cell::Options(_))));
+    // What is left is consistent with the solution.
+    let mut solved = input;
+    solved.solve().expect("error finding solution");
+    assert_board(solved, input);
+}
+
+#[test]
+fn check_solve_report() {
+    // Logic alone solves an easy puzzle.
+    let mut input = Board::<9>::parse_line(
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079",
+    )
+    .expect("building board line");
+    assert_eq!(input.solve_report(), Ok(false));
+    assert!(input.is_complete());
+
+    // But the puzzle from check_solve_hardest needs guessing.
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    assert_eq!(input.solve_report(), Ok(true));
+    assert!(input.is_complete());
+}
+
+#[test]
+fn check_solve_verified() {
+    // The solvable puzzles from the other tests, each on a single line.
+    let puzzles = [
+        &GOOD_BOARD_ZEROS.replace("\n", "")[..],
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079",
+        "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+        "049751863105869742860243951786012495523904617914570238471625089698137504352498170",
+        "040701003130000040800000950080302005000080000900506030071000009090000024300408070",
+        "000005430004000069001300000000879040600230000000060008006000007150000080030900000",
+    ];
+    for puzzle in puzzles.iter() {
+        let mut input = Board::<9>::parse_line(puzzle).expect("building board line");
+        input.solve_verified().expect("error finding solution");
+        assert!(input.is_complete());
+    }
+
+    let mut input = Board::<4>::parse(GOOD_BOARD_4X4.to_string()).expect("good board 4x4");
+    input.solve_verified().expect("error finding solution");
+
+    // Failures from solve are passed through unchanged.
+    let mut input = Board::<9>::parse_line(
+        "123456780000000009000000000000000000000000000000000000000000000000000000000000000",
+    )
+    .expect("building board line");
+    assert_eq!(
+        input.solve_verified(),
+        Err(SudokuError::Underdetermined {
+            clues: 9,
+            wanted: 17
+        })
+    );
+}
+
+#[test]
+fn check_solve_timed() {
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    let elapsed = input.solve_timed().expect("error finding solution");
+    assert!(elapsed >= std::time::Duration::ZERO);
+    assert!(input.is_complete());
+
+    // Failures are passed through unchanged.
+    let mut input = Board::<9>::parse_line(
+        "123456780000000009000000000000000000000000000000000000000000000000000000000000000",
+    )
+    .expect("building board line");
+    assert_eq!(
+        input.solve_timed(),
+        Err(SudokuError::Underdetermined {
+            clues: 9,
+            wanted: 17
+        })
+    );
+}
+
+#[test]
+fn check_givens() {
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    input.solve().expect("error finding solution");
+
+    // The clues are givens, but the cells filled in by the solver are not.
+    assert!(input.is_given(0, 2));
+    assert!(input.is_given(8, 6));
+    assert!(!input.is_given(0, 0));
+    assert!(!input.is_given(8, 8));
+    assert!(matches!(input.cells[0][0], Cell::Value(_)));
+}
+
+#[test]
+fn check_reset() {
+    let parsed = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+
+    let mut input = parsed;
+    input.solve().expect("error finding solution");
+    assert!(input.is_complete());
+    assert_ne!(input, parsed);
+
+    // Resetting should clear everything the solver filled in, leaving just the givens.
+    input.reset();
+    assert_eq!(input, parsed);
+}
+
+#[test]
+fn check_fill_candidates() {
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    input.fill_candidates();
+
+    // The top-left cell sees 5 and 3 in its row, 8 and 4 in its column, and 7 in its subsquare.
+    assert_eq!(
+        input.cells[0][0],
+        Cell::Options(bitset::BitSet::new(&[1, 2, 6, 9]))
+    );
+    // Givens are left alone.
+    assert_eq!(input.cells[0][2], Cell::Value(5));
+    // Nothing is solved along the way.
+    assert_eq!(input.count_filled(), 23);
+}
+
+#[test]
+fn check_propagate() {
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    let mut filled = input;
+    filled.fill_candidates();
+
+    // The first pass narrows the options just as filling in the candidates does, after which
+    // nothing changes.
+    assert_eq!(input.propagate(), Ok(true));
+    assert_eq!(input, filled);
+    assert_eq!(input.propagate(), Ok(false));
+    assert_eq!(input, filled);
+    assert_eq!(input.count_filled(), 23);
+
+    // A cell left without options is an error.
+    let mut input = Board::<9>::parse_line(
+        "123456780000000009000000000000000000000000000000000000000000000000000000000000000",
+    )
+    .expect("building board line");
+    assert_eq!(
+        input.propagate(),
+        Err(SudokuError::NoOptions { row: 0, col: 8 })
+    );
+}
+
+#[test]
+fn check_candidates() {
+    let input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+
+    // This cell sees 1, 7 and 6 in its row, 3, 2 and 5 in its column, and 5, 7 and 2 in its
+    // subsquare.
+    assert_eq!(
+        input.candidates(4, 3).expect("cell on the board"),
+        BitSet::new(&[4, 8, 9])
+    );
+    // Solved cells have no candidates.
+    assert!(input.candidates(0, 2).expect("cell on the board").empty());
+    // Asking doesn't change the board.
+    assert_eq!(input.cells[4][3], Cell::Options(BitSet::all(9)));
+}
+
+#[test]
+fn check_all_candidates() {
+    let input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    let all = input.all_candidates();
+    for (ridx, cidx) in [(0, 0), (4, 3), (8, 8)] {
+        assert_eq!(
+            all[ridx][cidx],
+            input.candidates(ridx, cidx).expect("cell on the board")
+        );
+    }
+    // Solved cells hold just their value.
+    assert_eq!(all[0][2], BitSet::new(&[5]));
+}
+
+#[test]
+fn check_place() {
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    input.fill_candidates();
+    for (ridx, cidx) in [(4, 2), (7, 3), (3, 4)] {
+        assert!(input.options(ridx, cidx).has(8));
+    }
+
+    input.place(4, 3, 8).expect("placing candidate");
+    assert_eq!(input.cells[4][3], Cell::Value(8));
+    // The row, column, and subsquare peers lose the placed value from their candidates.
+    for (ridx, cidx) in [(4, 2), (7, 3), (3, 4)] {
+        match input.cells[ridx][cidx] {
+            Cell::Options(opts) => assert!(!opts.has(8), "({}, {}) kept 8", ridx, cidx),
+            Cell::Value(v) => panic!("({}, {}) unexpectedly holds {}", ridx, cidx, v),
+        }
+    }
+    // Other cells keep theirs.
+    match input.cells[0][0] {
+        Cell::Options(opts) => assert!(opts.has(6)),
+        Cell::Value(v) => panic!("(0, 0) unexpectedly holds {}", v),
+    }
+
+    // Values that are out of range or break the rules are rejected.
+    assert_eq!(
+        input.place(4, 4, 10),
+        Err(SudokuError::InvalidValue {
+            row: 4,
+            col: 4,
+            value: 10
+        })
+    );
+    let before = input;
+    assert_eq!(input.place(4, 5, 8), Err(SudokuError::Duplicate(8)));
+    // The board is left as it was.
+    assert_eq!(input.cells[4][5], before.cells[4][5]);
+    assert_eq!(input, before);
+    input.check().expect("Failed to validate board.");
+
+    // Solved cells, given or placed, can't be placed over, even with a value that fits.
+    assert!(input.is_given(0, 2));
+    assert_eq!(
+        input.place(0, 2, 5),
+        Err(SudokuError::AlreadySolved { row: 0, col: 2 })
+    );
+    assert!(!input.can_place(4, 3, 8));
+    assert_eq!(
+        input.place(4, 3, 8),
+        Err(SudokuError::AlreadySolved { row: 4, col: 3 })
+    );
+    assert_eq!(input, before);
+
+    // Until they are cleared.
+    input.clear(4, 3).expect("clearing placed cell");
+    assert!(input.can_place(4, 3, 8));
+    input.place(4, 3, 8).expect("placing candidate");
+}
+
+#[test]
+fn check_unit_complete() {
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    input.solve().expect("error finding solution");
+    for idx in 0..9 {
+        assert!(input.is_row_complete(idx));
+        assert!(input.is_col_complete(idx));
+        assert!(input.is_box_complete(idx));
+    }
+    assert!(!input.is_row_complete(9));
+
+    // Clearing a cell leaves its units incomplete, and the rest alone.
+    input.clear(4, 3).expect("clearing placed value");
+    assert!(!input.is_row_complete(4));
+    assert!(!input.is_col_complete(3));
+    assert!(!input.is_box_complete(4));
+    assert!(input.is_row_complete(3));
+    assert!(input.is_col_complete(4));
+    assert!(input.is_box_complete(3));
+}
+
+#[test]
+fn check_can_place() {
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+
+    // There's a 5 in the top row, but no 6 among the peers of the top-left cell.
+    assert!(input.can_place(0, 0, 6));
+    assert!(!input.can_place(0, 0, 5));
+    // Solved cells, and anything out of range, are ruled out.
+    assert!(!input.can_place(0, 2, 5));
+    assert!(!input.can_place(0, 0, 0));
+    assert!(!input.can_place(0, 0, 10));
+    assert!(!input.can_place(9, 0, 6));
+
+    // It agrees with place.
+    input.place(0, 0, 6).expect("placing candidate");
+    assert!(!input.can_place(0, 1, 6));
+    assert_eq!(input.place(0, 1, 6), Err(SudokuError::Duplicate(6)));
+}
+
+#[test]
+fn check_clear() {
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    input.fill_candidates();
+    let filled = input;
+
+    // Clearing a placed value reopens it in the peers, leaving the board as it was.
+    input.place(4, 3, 8).expect("placing candidate");
+    input.clear(4, 3).expect("clearing placed value");
+    assert_eq!(input, filled);
+    for (ridx, cidx) in [(4, 2), (7, 3), (3, 4)] {
+        assert!(input.options(ridx, cidx).has(8));
+    }
+
+    // Givens can be cleared, and stop being givens.
+    assert_eq!(input.cells[0][2], Cell::Value(5));
+    input.clear(0, 2).expect("clearing given");
+    assert!(!input.is_given(0, 2));
+    assert!(input.candidates(0, 2).expect("cell on the board").has(5));
+    assert!(input.options(0, 0).has(5));
+    // But a value still held by another peer stays out.
+    input.place(0, 2, 1).expect("placing candidate");
+    input.place(4, 2, 5).expect("placing candidate");
+    input.clear(0, 2).expect("clearing placed value");
+    assert!(!input.candidates(0, 2).expect("cell on the board").has(5));
+    assert!(input.options(0, 0).has(1));
+
+    // Clearing an empty cell does nothing.
+    let before = input;
+    input.clear(0, 0).expect("clearing empty cell");
+    assert_eq!(input, before);
+    assert_eq!(
+        input.clear(9, 0),
+        Err(SudokuError::OutOfBounds { row: 9, col: 0 })
+    );
+}
+
+#[test]
+fn check_out_of_bounds() {
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    let saved = input;
+
+    assert_eq!(input.get(0, 2), Ok(Cell::Value(5)));
+    assert_eq!(
+        input.get(9, 0),
+        Err(SudokuError::OutOfBounds { row: 9, col: 0 })
+    );
+    assert_eq!(
+        input.place(0, 9, 1),
+        Err(SudokuError::OutOfBounds { row: 0, col: 9 })
+    );
+    assert_eq!(
+        input.candidates(9, 9),
+        Err(SudokuError::OutOfBounds { row: 9, col: 9 })
+    );
+    assert!(!input.is_given(9, 0));
+    assert_eq!(
+        input.check_row(9),
+        Err(SudokuError::InvalidUnit(Unit::Row(9)))
+    );
+    assert_eq!(
+        input.check_col(9),
+        Err(SudokuError::InvalidUnit(Unit::Col(9)))
+    );
+    assert_eq!(
+        input.check_box(9),
+        Err(SudokuError::InvalidUnit(Unit::Box(9)))
+    );
+    // Nothing was changed along the way.
+    assert_eq!(input.cells, saved.cells);
+}
+
+// Place value at (row, col) without checking, and confirm that only the unit holding the
+// duplicate fails its check.
+fn assert_unit_fails(row: usize, col: usize, value: usize, failing: &str) {
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    input.put(row, col, Cell::Value(value));
+    let region = input.regions.of(row, col);
+
+    assert_eq!(input.check_row(row).is_err(), failing == "row");
+    assert_eq!(input.check_col(col).is_err(), failing == "col");
+    assert_eq!(input.check_box(region).is_err(), failing == "box");
+    assert_eq!(input.check(), Err(SudokuError::Duplicate(value)));
+
+    // The other units are unaffected.
+    for idx in (0..9).filter(|&idx| idx != row) {
+        input.check_row(idx).expect("other rows are fine");
+    }
+    for idx in (0..9).filter(|&idx| idx != col) {
+        input.check_col(idx).expect("other columns are fine");
+    }
+    for idx in (0..9).filter(|&idx| idx != region) {
+        input.check_box(idx).expect("other boxes are fine");
+    }
+}
+
+#[test]
+fn check_units() {
+    // There's already a 2 in row 1, an 8 in column 0, and a 5 in box 0.
+    assert_unit_fails(1, 4, 2, "row");
+    assert_unit_fails(8, 0, 8, "col");
+    assert_unit_fails(1, 1, 5, "box");
+}
+
+// Check that the cached masks of unplaced values match the values on the board.
+fn assert_unplaced(b: &Board) {
+    for idx in 0..9 {
+        assert_eq!(b.row_unplaced[idx], views::mask::<9>(b.row(idx)));
+        assert_eq!(b.col_unplaced[idx], views::mask::<9>(b.col(idx)));
+        assert_eq!(b.region_unplaced[idx], views::mask::<9>(b.region(idx)));
+    }
+}
+
+#[test]
+fn check_unplaced() {
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    assert_unplaced(&input);
+
+    input.solve().expect("error finding solution");
+    assert_unplaced(&input);
+    assert_unplaced(&input.rotate90());
+    assert_unplaced(&input.canonicalize());
+
+    input.reset();
+    assert_unplaced(&input);
+
+    assert_unplaced(&Board::generate_puzzle(42, 30));
+}
+
 #[test]
 fn check_iterators() {
     let b = Board::new([
@@ -199,7 +1242,7 @@ fn check_iterators() {
     for ss_cidx in 0..3 {
         for ss_ridx in 0..3 {
             let mut idx = 0;
-            for got in b.subsquare(ss_ridx, ss_cidx) {
+            for got in b.region(ss_ridx * 3 + ss_cidx) {
                 let want = b.cells[ss_ridx * 3 + idx / 3][ss_cidx * 3 + idx % 3];
                 assert_eq!(
                     got, want,
@@ -237,6 +1280,212 @@ fn check_solve_fails() {
     }
 }
 
+#[test]
+fn check_solve_all_options_fail() {
+    // The puzzle from check_solve_hardest, with a 2 added in the top-left corner.  Logic alone
+    // can't show that this is wrong, so the solver has to guess before giving up.
+    let mut input = Board::<9>::parse_line(
+        "205300000800000020070010500400005300010070006003200080060500009004000030000009700",
+    )
+    .expect("building board line");
+    let before = input;
+    assert_eq!(input.solve(), Err(SudokuError::Unsolvable));
+
+    // Everything is undone, leaving the board exactly as it was given.
+    assert_eq!(input.cells, before.cells);
+    assert_eq!(input.count_filled(), 24);
+    assert_unplaced(&input);
+}
+
+#[test]
+fn check_solve_failure_restores() {
+    // Logic gets some way into this board before finding that a cell has no options, and
+    // none of that progress is kept.
+    let mut input = Board::<9>::parse_line(
+        "123456780000000009000000000000000000000000000000000000000000000000000000000000000",
+    )
+    .expect("building board line");
+    input.fill_candidates();
+    let before = input;
+    assert!(input.solve_with_min_clues(0).is_err());
+    assert_eq!(input.cells, before.cells);
+    assert_eq!(input.to_state(), before.to_state());
+    assert_unplaced(&input);
+
+    // The same goes for the solver giving up.
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    let before = input;
+    assert_eq!(
+        input.solve_with(&[&Churn]),
+        Err(SudokuError::DidNotConverge)
+    );
+    assert_eq!(input.cells, before.cells);
+}
+
+#[test]
+fn check_solve_contradictory() {
+    // The top-right corner can only hold a 9, but there's already one in its column.
+    let mut input = Board::<9>::parse_line(
+        "123456780000000009000000000000000000000000000000000000000000000000000000000000000",
+    )
+    .expect("building board line");
+    // The error names the cell that is left without options.
+    assert_eq!(
+        input.solve_with_min_clues(0),
+        Err(SudokuError::NoOptions { row: 0, col: 8 })
+    );
+}
+
+// A strategy that blindly puts a 7 in two cells of the middle row.
+struct Clash;
+
+impl Strategy for Clash {
+    fn apply(&self, board: &mut Board) -> Result<bool, SudokuError> {
+        board.put(4, 1, Cell::Value(7));
+        board.put(4, 6, Cell::Value(7));
+        Ok(true)
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::NakedSingle
+    }
+}
+
+#[test]
+fn check_solve_conflict() {
+    // The error names the unit holding the duplicate.
+    let mut input = Board::new([[0; 9]; 9]).expect("building board literal");
+    assert_eq!(
+        input.solve_with(&[&Clash]),
+        Err(SudokuError::Conflict {
+            value: 7,
+            unit: Unit::Row(4)
+        })
+    );
+}
+
+// A strategy that claims to make progress on every pass without doing anything, so the solver
+// never converges.
+struct Churn;
+
+impl Strategy for Churn {
+    fn apply(&self, _board: &mut Board) -> Result<bool, SudokuError> {
+        Ok(true)
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::NakedSingle
+    }
+}
+
+#[test]
+fn check_solve_did_not_converge() {
+    // Arto Inkala's "Everest" puzzle is hard, but it can be solved.
+    let everest = Board::<9>::parse_line(
+        "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+    )
+    .expect("building board line");
+    let mut input = everest;
+    input.solve().expect("error finding solution");
+
+    // Running out of iterations isn't the same as having no solution.
+    let mut input = everest;
+    assert_eq!(
+        input.solve_with(&[&Churn]),
+        Err(SudokuError::DidNotConverge)
+    );
+}
+
+#[test]
+fn check_solve_solved() {
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    input.solve().expect("error finding solution");
+    let solved = input;
+
+    // Solving again does nothing, not even applying the strategies.
+    let counter = fixtures::Counter(std::cell::Cell::new(0));
+    assert_eq!(
+        input.solve_with_order(&[&counter], ValueOrder::Ascending),
+        Ok(0)
+    );
+    assert_eq!(counter.0.get(), 0);
+    assert_eq!(input, solved);
+}
+
+#[test]
+fn check_solve_invalid() {
+    // A board that breaks the rules reports why, rather than trying to solve it.
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    input.put(0, 0, Cell::Value(5));
+    let counter = fixtures::Counter(std::cell::Cell::new(0));
+    assert_eq!(
+        input.solve_with(&[&counter]),
+        Err(SudokuError::Duplicate(5))
+    );
+    assert_eq!(counter.0.get(), 0);
+    assert_eq!(input.solve(), Err(SudokuError::Duplicate(5)));
+}
+
+#[test]
+fn check_solve_one_diff() {
+    // A solved board with its diagonal blanked out, and the top right corner too.
+    let mut values = [
+        [2, 4, 9, 7, 5, 1, 8, 6, 3],
+        [1, 3, 5, 8, 6, 9, 7, 4, 2],
+        [8, 6, 7, 2, 4, 3, 9, 5, 1],
+        [7, 8, 6, 3, 1, 2, 4, 9, 5],
+        [5, 2, 3, 9, 8, 4, 6, 1, 7],
+        [9, 1, 4, 5, 7, 6, 2, 3, 8],
+        [4, 7, 1, 6, 2, 5, 3, 8, 9],
+        [6, 9, 8, 1, 3, 7, 5, 2, 4],
+        [3, 5, 2, 4, 9, 8, 1, 7, 6],
+    ];
+    for (diag, row) in values.iter_mut().enumerate() {
+        row[diag] = 0;
+    }
+    values[0][8] = 0;
+    let mut input = Board::new(values).expect("building board literal");
+
+    // Every blank is a naked single, so they all change in one pass, and nothing else does.
+    let (options, changed) = input.solve_one_diff().expect("error during solve_one_diff");
+    assert_eq!(options, 0);
+    let mut wanted: Vec<(usize, usize)> = (0..9).map(|diag| (diag, diag)).collect();
+    wanted.insert(1, (0, 8));
+    assert_eq!(changed, wanted);
+
+    // Once solved, nothing changes.
+    assert_eq!(input.solve_one_diff(), Ok((0, vec![])));
+}
+
+#[test]
+fn check_solve_one_counts() {
+    // The 1s in the other rows and columns of the top left box leave (0, 0) as the only cell
+    // there that can hold a 1, though the cell itself still has every option.
+    let mut values = [[0; 9]; 9];
+    values[1][4] = 1;
+    values[2][7] = 1;
+    values[4][1] = 1;
+    values[7][2] = 1;
+    let mut input = Board::new(values).expect("building board literal");
+    assert_eq!(input.solve_one_counts(), Ok((76, 0, 1)));
+    assert_eq!(input.get(0, 0), Ok(Cell::Value(1)));
+
+    // A solved board with its diagonal blanked out is all naked singles.
+    let mut input = Board::new([
+        [0, 4, 9, 7, 5, 1, 8, 6, 3],
+        [1, 0, 5, 8, 6, 9, 7, 4, 2],
+        [8, 6, 0, 2, 4, 3, 9, 5, 1],
+        [7, 8, 6, 0, 1, 2, 4, 9, 5],
+        [5, 2, 3, 9, 0, 4, 6, 1, 7],
+        [9, 1, 4, 5, 7, 0, 2, 3, 8],
+        [4, 7, 1, 6, 2, 5, 0, 8, 9],
+        [6, 9, 8, 1, 3, 7, 5, 0, 4],
+        [3, 5, 2, 4, 9, 8, 1, 7, 0],
+    ])
+    .expect("building board literal");
+    assert_eq!(input.solve_one_counts(), Ok((0, 9, 0)));
+}
+
 #[test]
 fn check_solve_super_easy() {
     let mut input = Board::new([
@@ -359,3 +1608,246 @@ fn check_solve_hardest() {
     assert_board(input, solution);
     assert_eq!(input, solution);
 }
+
+#[test]
+fn check_solve_box_line_reduction() {
+    let solution = Board::new([
+        [8, 6, 2, 7, 9, 5, 4, 3, 1],
+        [3, 7, 4, 1, 8, 2, 5, 6, 9],
+        [5, 9, 1, 3, 4, 6, 8, 7, 2],
+        [2, 1, 5, 8, 7, 9, 6, 4, 3],
+        [6, 8, 9, 2, 3, 4, 7, 1, 5],
+        [7, 4, 3, 5, 6, 1, 2, 9, 8],
+        [9, 2, 6, 4, 1, 8, 3, 5, 7],
+        [1, 5, 7, 6, 2, 3, 9, 8, 4],
+        [4, 3, 8, 9, 5, 7, 1, 2, 6],
+    ])
+    .expect("building board literal");
+    solution.check().expect("Failed to validate board.");
+
+    // Naked and hidden singles alone stall on this board, but box-line
+    // reduction unblocks them.
+    let mut input = fixtures::harder();
+    input.check().expect("Failed to validate board.");
+
+    // Without box-line reduction, the singles run out of progress with cells left unsolved.
+    let mut singles = input;
+    assert_ne!(
+        fixtures::stall(&mut singles, &[&NakedSingles, &HiddenSingles]),
+        0
+    );
+
+    // Every pass of the solver should make progress until it closes, so
+    // we never need to fall back on guessing.
+    loop {
+        let (options, changed) = input.solve_one().expect("error during solve_one");
+        if options == 0 {
+            break;
+        }
+        assert!(changed, "solver stalled: {:#?}", input);
+    }
+
+    assert_eq!(input, solution);
+}
+
+#[test]
+fn check_is_proper() {
+    let input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    assert!(input.is_proper());
+
+    // Without the clues on its top row, the puzzle has many solutions.
+    let mut multiple = input;
+    for cidx in 0..9 {
+        multiple.put(0, cidx, Cell::Options(BitSet::all(9)));
+    }
+    multiple.check().expect("Failed to validate board.");
+    assert!(!multiple.is_proper());
+
+    // An empty board is valid, but anything solves it.
+    let empty = Board::<9>::new([[0; 9]; 9]).expect("building board literal");
+    empty.check().expect("Failed to validate board.");
+    assert!(!empty.is_proper());
+
+    // A board that breaks the rules isn't proper.
+    let mut duplicate = input;
+    duplicate.put(0, 0, Cell::Value(5));
+    assert!(!duplicate.is_proper());
+
+    // Nor is one that follows them, but can't be solved.
+    let contradictory = Board::<9>::parse_line(
+        "123456780000000009000000000000000000000000000000000000000000000000000000000000000",
+    )
+    .expect("building board line");
+    contradictory.check().expect("Failed to validate board.");
+    assert!(!contradictory.is_proper());
+}
+
+const GOOD_BOARD_4X4: &str = "\
+1204
+0010
+0100
+4001";
+
+#[test]
+fn check_debug() {
+    let mut input = Board::<4>::parse(GOOD_BOARD_4X4.to_string()).expect("good board 4x4");
+    input.eliminate(1, 0, 1);
+    input.eliminate(1, 0, 4);
+    assert_eq!(
+        format!("{:?}", input),
+        "
+1      2      [1234] 4
+[23]   [1234] 1      [1234]
+[1234] 1      [1234] [1234]
+4      [1234] [1234] 1
+"
+    );
+}
+
+#[test]
+fn check_solve_4x4() {
+    let solution = Board::new([[1, 2, 3, 4], [3, 4, 1, 2], [2, 1, 4, 3], [4, 3, 2, 1]])
+        .expect("building board literal");
+    solution.check().expect("Failed to validate board.");
+
+    let mut input = Board::<4>::parse(GOOD_BOARD_4X4.to_string()).expect("good board 4x4");
+    input.check().expect("Failed to validate board.");
+    assert!(input.has_unique_solution());
+
+    input.solve().expect("error finding solution");
+    assert_eq!(input, solution);
+
+    // Values larger than the board are invalid.
+    if let Ok(b) = Board::new([[5, 0, 0, 0], [0; 4], [0; 4], [0; 4]]) {
+        panic!("wanted error due to bad value: 5, got: {:#?}", b)
+    }
+    // So are duplicates within a 2x2 subsquare.
+    if let Ok(b) = Board::new([[1, 0, 0, 0], [0, 1, 0, 0], [0; 4], [0; 4]]) {
+        panic!("wanted error due to duplicate in subsquare, got: {:#?}", b)
+    }
+}
+
+#[test]
+fn check_solve_16x16() {
+    let mut input = Board::new([
+        [0, 2, 0, 4, 0, 6, 0, 8, 9, 10, 11, 12, 0, 0, 0, 0],
+        [5, 6, 0, 8, 0, 0, 11, 0, 13, 0, 15, 16, 0, 0, 3, 4],
+        [0, 10, 11, 0, 13, 0, 15, 16, 1, 2, 0, 4, 0, 0, 7, 0],
+        [13, 14, 15, 0, 0, 2, 3, 4, 0, 0, 7, 8, 9, 0, 0, 0],
+        [0, 0, 4, 5, 6, 0, 0, 9, 10, 0, 12, 13, 0, 15, 0, 1],
+        [0, 0, 0, 9, 10, 0, 12, 13, 14, 15, 0, 0, 0, 3, 4, 0],
+        [10, 0, 12, 13, 0, 15, 0, 0, 2, 3, 4, 5, 0, 7, 8, 9],
+        [0, 15, 16, 0, 0, 3, 0, 0, 6, 7, 0, 9, 10, 0, 12, 0],
+        [0, 4, 0, 0, 0, 8, 0, 0, 11, 0, 13, 14, 15, 16, 0, 0],
+        [7, 8, 9, 10, 11, 12, 0, 14, 15, 16, 1, 2, 3, 0, 0, 0],
+        [11, 0, 13, 14, 0, 0, 1, 0, 0, 0, 0, 6, 7, 0, 0, 10],
+        [15, 16, 0, 0, 3, 0, 5, 6, 7, 0, 9, 0, 0, 12, 0, 0],
+        [4, 0, 6, 7, 0, 0, 10, 11, 12, 13, 14, 0, 0, 1, 0, 3],
+        [8, 0, 10, 0, 0, 0, 14, 15, 16, 1, 0, 0, 4, 0, 6, 7],
+        [12, 13, 14, 15, 0, 1, 2, 0, 4, 5, 6, 0, 8, 9, 0, 11],
+        [16, 1, 2, 3, 4, 5, 0, 7, 8, 0, 0, 0, 0, 0, 14, 15],
+    ])
+    .expect("building board literal");
+    input.check().expect("Failed to validate board.");
+
+    let solution = Board::new([
+        [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+        [5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 1, 2, 3, 4],
+        [9, 10, 11, 12, 13, 14, 15, 16, 1, 2, 3, 4, 5, 6, 7, 8],
+        [13, 14, 15, 16, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+        [2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 1],
+        [6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 1, 2, 3, 4, 5],
+        [10, 11, 12, 13, 14, 15, 16, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+        [14, 15, 16, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13],
+        [3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 1, 2],
+        [7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 1, 2, 3, 4, 5, 6],
+        [11, 12, 13, 14, 15, 16, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+        [15, 16, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14],
+        [4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 1, 2, 3],
+        [8, 9, 10, 11, 12, 13, 14, 15, 16, 1, 2, 3, 4, 5, 6, 7],
+        [12, 13, 14, 15, 16, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+        [16, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    ])
+    .expect("building board literal");
+    solution.check().expect("Failed to validate board.");
+
+    input.solve().expect("error finding solution");
+    assert_eq!(input, solution);
+}
+
+#[test]
+fn check_blanks_by_constraint() {
+    // The top-right corner can only hold a 9, since its column has an 8, and the cell next to
+    // it an 8 or 9.
+    let input = Board::<9>::parse_line(
+        "123456700000000000000000000000000008000000000000000000000000000000000000000000000",
+    )
+    .expect("building board line");
+    let blanks = input.blanks_by_constraint();
+    assert_eq!(blanks.len(), 81 - 8);
+    assert_eq!(blanks[0], (0, 8, 1));
+    assert_eq!(blanks[1], (0, 7, 2));
+    assert!(blanks.windows(2).all(|pair| pair[0].2 <= pair[1].2));
+
+    // A solved board has no blanks.
+    let mut solved = input;
+    solved
+        .solve_with_min_clues(0)
+        .expect("error finding solution");
+    assert!(solved.blanks_by_constraint().is_empty());
+}
+
+#[test]
+fn check_solve_underdetermined() {
+    // A board with a single clue is refused before any searching.
+    let mut values = [[0; 9]; 9];
+    values[4][4] = 5;
+    let mut input = Board::new(values).expect("building board literal");
+    assert_eq!(
+        input.solve(),
+        Err(SudokuError::Underdetermined {
+            clues: 1,
+            wanted: 17
+        })
+    );
+    assert_eq!(input.count_filled(), 1);
+
+    // The minimum can be raised or lowered.
+    let mut input = Board::<9>::parse(GOOD_BOARD_ZEROS.to_string()).expect("good board zeros");
+    assert_eq!(
+        input.solve_with_min_clues(30),
+        Err(SudokuError::Underdetermined {
+            clues: 23,
+            wanted: 30
+        })
+    );
+    input
+        .solve_with_min_clues(21)
+        .expect("error finding solution");
+    assert!(input.is_complete());
+}
+
+#[test]
+fn check_solve_empty() {
+    // With no clues at all, the solver has to guess over and over, with each guess nested
+    // inside the last.  solve refuses to try, but the minimum can be lifted.
+    let mut input = Board::new([[0; 9]; 9]).expect("building board literal");
+    assert_eq!(
+        input.solve(),
+        Err(SudokuError::Underdetermined {
+            clues: 0,
+            wanted: 17
+        })
+    );
+    input
+        .solve_with_min_clues(0)
+        .expect("error finding solution");
+    input.check().expect("Failed to validate board.");
+    assert!(input.is_complete());
+
+    // The same goes for a larger board, which nests its guesses even more deeply.
+    let mut input = Board::new([[0; 16]; 16]).expect("building board literal");
+    input.solve().expect("error finding solution");
+    input.check().expect("Failed to validate board.");
+    assert!(input.is_complete());
+}
@@ -0,0 +1,92 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use super::{Board, SudokuError, Technique, ValueOrder};
+
+// A step taken while solving: the value placed in (or, for a retraction, removed from) a cell,
+// and the technique behind it.  A Retract undoes the Guess at that cell along with every move
+// since it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Move {
+    pub row: usize,
+    pub col: usize,
+    pub value: usize,
+    pub technique: Technique,
+}
+
+impl<const N: usize> Board<N> {
+    // Solve the board with the default strategies, returning the moves made in order.
+    pub fn solve_with_log(&mut self) -> Result<Vec<Move>, SudokuError> {
+        let mut moves = Vec::new();
+        self.solve_observed(
+            Board::<N>::DEFAULT_STRATEGIES,
+            ValueOrder::Ascending,
+            Board::<N>::MAX_PASSES,
+            Some(&mut |m| moves.push(m)),
+        )?;
+        Ok(moves)
+    }
+
+    // Count how many of the cells in the solution each technique solved, without changing
+    // the board.  Moves undone by a retraction don't count, so the counts add up to the
+    // number of cells that were unsolved.
+    pub fn technique_histogram(&self) -> Result<BTreeMap<Technique, usize>, SudokuError> {
+        let mut board = *self;
+        let mut kept: Vec<Move> = Vec::new();
+        for m in board.solve_with_log()? {
+            if m.technique == Technique::Retract {
+                // Drop everything back to and including the guess being retracted.
+                while let Some(undone) = kept.pop() {
+                    if undone.technique == Technique::Guess
+                        && (undone.row, undone.col) == (m.row, m.col)
+                    {
+                        break;
+                    }
+                }
+            } else {
+                kept.push(m);
+            }
+        }
+
+        let mut histogram = BTreeMap::new();
+        for m in kept {
+            *histogram.entry(m.technique).or_insert(0) += 1;
+        }
+        Ok(histogram)
+    }
+
+    // Solve the board with the default strategies, calling on_place with the (row, column,
+    // value) of each cell as it is solved, including speculatively, e.g. to animate progress.
+    pub fn solve_with_observer<F: FnMut(usize, usize, usize)>(
+        &mut self,
+        on_place: F,
+    ) -> Result<(), SudokuError> {
+        self.solve_with_observers(on_place, |_, _, _| {})
+    }
+
+    // Solve the board as solve_with_observer does, also calling on_retract with the guess at
+    // (row, column, value) when it fails.  The cells placed since that guess are undone too.
+    pub fn solve_with_observers<P, R>(
+        &mut self,
+        mut on_place: P,
+        mut on_retract: R,
+    ) -> Result<(), SudokuError>
+    where
+        P: FnMut(usize, usize, usize),
+        R: FnMut(usize, usize, usize),
+    {
+        self.solve_observed(
+            Board::<N>::DEFAULT_STRATEGIES,
+            ValueOrder::Ascending,
+            Board::<N>::MAX_PASSES,
+            Some(&mut |m: Move| match m.technique {
+                Technique::Retract => on_retract(m.row, m.col, m.value),
+                _ => on_place(m.row, m.col, m.value),
+            }),
+        )
+        .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests;
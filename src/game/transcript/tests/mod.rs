@@ -0,0 +1,150 @@
+use super::*;
+use crate::game::Cell;
+
+// Play the moves onto the board, undoing guesses as they are retracted.
+fn replay(mut board: Board, moves: &[Move]) -> Board {
+    let mut guesses: Vec<(Board, Move)> = Vec::new();
+    for &m in moves {
+        match m.technique {
+            Technique::Retract => {
+                let (saved, guess) = guesses.pop().expect("retracting without a guess");
+                assert_eq!((guess.row, guess.col, guess.value), (m.row, m.col, m.value));
+                board = saved;
+            }
+            technique => {
+                if technique == Technique::Guess {
+                    guesses.push((board, m));
+                }
+                board
+                    .set(m.row, m.col, Cell::Value(m.value))
+                    .expect("replaying move");
+            }
+        }
+    }
+    board
+}
+
+#[test]
+fn check_solve_with_log() {
+    // Arto Inkala's "Everest" puzzle, which needs a lot of guessing.
+    let puzzle = Board::<9>::parse_line(
+        "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+    )
+    .expect("building board line");
+
+    let mut solved = puzzle;
+    let moves = solved.solve_with_log().expect("error finding solution");
+    assert!(solved.is_complete());
+
+    let count = |technique| moves.iter().filter(|m| m.technique == technique).count();
+    assert!(count(Technique::Guess) > 0);
+    assert!(count(Technique::Retract) > 0);
+    assert!(count(Technique::NakedSingle) > 0);
+
+    assert_eq!(replay(puzzle, &moves), solved);
+}
+
+#[test]
+fn check_solve_with_log_logical() {
+    // An easy puzzle is solved without guessing, one move per blank cell.
+    let puzzle = Board::<9>::parse_line(
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079",
+    )
+    .expect("building board line");
+
+    let mut solved = puzzle;
+    let moves = solved.solve_with_log().expect("error finding solution");
+    assert_eq!(moves.len(), 81 - puzzle.count_filled());
+    assert!(moves.iter().all(|m| m.technique != Technique::Guess));
+    assert_eq!(replay(puzzle, &moves), solved);
+}
+
+#[test]
+fn check_technique_histogram() {
+    // An easy puzzle is solved without guessing, so every blank cell is down to logic.
+    let puzzle = Board::<9>::parse_line(
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079",
+    )
+    .expect("building board line");
+    let histogram = puzzle
+        .technique_histogram()
+        .expect("error finding solution");
+    assert_eq!(
+        histogram.values().sum::<usize>(),
+        81 - puzzle.count_filled()
+    );
+    assert!(histogram[&Technique::NakedSingle] > 0);
+    assert!(!histogram.contains_key(&Technique::Guess));
+    assert!(!histogram.contains_key(&Technique::Retract));
+
+    // Guesses that fail don't count, so the cells still add up.
+    let everest = Board::<9>::parse_line(
+        "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+    )
+    .expect("building board line");
+    let histogram = everest
+        .technique_histogram()
+        .expect("error finding solution");
+    assert_eq!(
+        histogram.values().sum::<usize>(),
+        81 - everest.count_filled()
+    );
+    assert!(histogram[&Technique::Guess] > 0);
+    // The board itself is left alone.
+    assert_eq!(everest.count_filled(), 21);
+}
+
+#[test]
+fn check_solve_with_observer() {
+    // An easy puzzle is solved without guessing, so each blank is placed exactly once.
+    let puzzle = Board::<9>::parse_line(
+        "530070000600195000098000060800060003400803001700020006060000280000419005000080079",
+    )
+    .expect("building board line");
+
+    let mut placed = puzzle;
+    let mut count = 0;
+    let mut solved = puzzle;
+    solved
+        .solve_with_observer(|row, col, value| {
+            count += 1;
+            placed
+                .set(row, col, Cell::Value(value))
+                .expect("placing value");
+        })
+        .expect("error finding solution");
+    assert_eq!(count, 81 - puzzle.count_filled());
+    assert_eq!(placed, solved);
+}
+
+#[test]
+fn check_solve_with_observers() {
+    // The callbacks see the same moves as the log, with retractions reported separately.
+    let puzzle = Board::<9>::parse_line(
+        "800000000003600000070090200050007000000045700000100030001000068008500010090000400",
+    )
+    .expect("building board line");
+    let mut logged = puzzle;
+    let moves = logged.solve_with_log().expect("error finding solution");
+    let of = |retract: bool| -> Vec<(usize, usize, usize)> {
+        moves
+            .iter()
+            .filter(|m| (m.technique == Technique::Retract) == retract)
+            .map(|m| (m.row, m.col, m.value))
+            .collect()
+    };
+
+    let mut placed = Vec::new();
+    let mut retracted = Vec::new();
+    let mut solved = puzzle;
+    solved
+        .solve_with_observers(
+            |row, col, value| placed.push((row, col, value)),
+            |row, col, value| retracted.push((row, col, value)),
+        )
+        .expect("error finding solution");
+    assert_eq!(placed, of(false));
+    assert_eq!(retracted, of(true));
+    assert!(!retracted.is_empty());
+    assert_eq!(solved, logged);
+}
@@ -0,0 +1,114 @@
+use super::bitset::BitSet;
+use super::regions::Regions;
+use super::{Board, Cell};
+
+impl<const N: usize> Board<N> {
+    // Rotate the board a quarter turn clockwise.
+    pub fn rotate90(&self) -> Board<N> {
+        self.transform(|ridx, cidx| (N - 1 - cidx, ridx))
+    }
+
+    // Reflect the board across its main diagonal, swapping rows with columns.
+    pub fn transpose(&self) -> Board<N> {
+        self.transform(|ridx, cidx| (cidx, ridx))
+    }
+
+    // Reflect the board left to right.
+    pub fn mirror_horizontal(&self) -> Board<N> {
+        self.transform(|ridx, cidx| (ridx, N - 1 - cidx))
+    }
+
+    // A canonical representative of the puzzles that differ from this one only by a
+    // relabeling of the values or a symmetry of the square.  Each of the eight rotations and
+    // reflections is relabeled so that values are numbered in order of first appearance
+    // (reading left to right and top to bottom), and the one whose values read smallest wins.
+    pub fn canonicalize(&self) -> Board<N> {
+        let rotations = [
+            *self,
+            self.rotate90(),
+            self.rotate90().rotate90(),
+            self.rotate90().rotate90().rotate90(),
+        ];
+        let mut best = self.relabel();
+        for board in rotations.iter() {
+            for candidate in [board.relabel(), board.mirror_horizontal().relabel()].iter() {
+                if candidate.values() < best.values() {
+                    best = *candidate;
+                }
+            }
+        }
+        best
+    }
+
+    // Renumber the values in order of their first appearance, followed by any values that
+    // don't appear in ascending order.
+    fn relabel(&self) -> Board<N> {
+        // The new label for each value (less one), or 0 if it hasn't been assigned one yet.
+        let mut labels = [0; N];
+        let mut next = 1;
+        for cell in self.cells.iter().flat_map(|row| row.iter()) {
+            if let Cell::Value(v) = *cell {
+                if labels[v - 1] == 0 {
+                    labels[v - 1] = next;
+                    next += 1;
+                }
+            }
+        }
+        for label in labels.iter_mut().filter(|label| **label == 0) {
+            *label = next;
+            next += 1;
+        }
+
+        let mut board = *self;
+        for cell in board.cells.iter_mut().flat_map(|row| row.iter_mut()) {
+            *cell = match *cell {
+                Cell::Value(v) => Cell::Value(labels[v - 1]),
+                Cell::Options(opts) => {
+                    Cell::Options(opts.foreach().fold(BitSet::new(&[]), |relabeled, v| {
+                        relabeled.set(labels[v - 1])
+                    }))
+                }
+            };
+        }
+        board.recompute_unplaced();
+        board
+    }
+
+    // The values of the board, with 0 for unsolved cells.
+    fn values(&self) -> [[usize; N]; N] {
+        let mut values = [[0; N]; N];
+        for (row, cells) in values.iter_mut().zip(self.cells.iter()) {
+            for (value, cell) in row.iter_mut().zip(cells.iter()) {
+                if let Cell::Value(v) = *cell {
+                    *value = v;
+                }
+            }
+        }
+        values
+    }
+
+    // Build a new board whose cell at (row, column) comes from the cell of this one at
+    // source(row, column).  The givens, regions, cages, and custom constraints move along with
+    // the cells, and since each transform maps the diagonals onto the diagonals and knight's
+    // moves onto knight's moves, the extra constraints still apply.
+    fn transform(&self, source: impl Fn(usize, usize) -> (usize, usize)) -> Board<N> {
+        let mut board = *self;
+        let mut regions = [[0; N]; N];
+        for (ridx, row) in regions.iter_mut().enumerate() {
+            for (cidx, region) in row.iter_mut().enumerate() {
+                let (src_ridx, src_cidx) = source(ridx, cidx);
+                board.cells[ridx][cidx] = self.cells[src_ridx][src_cidx];
+                board.givens[ridx][cidx] = self.givens[src_ridx][src_cidx];
+                *region = self.regions.of(src_ridx, src_cidx);
+            }
+        }
+        board.regions = Regions::from_map(regions).expect("transformed regions are valid");
+        board.cages = self.cages.moved(&source);
+        board.sets = self.sets.moved(source);
+        board.recompute_unplaced();
+        board
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,129 @@
+use super::*;
+use crate::game::Cell;
+
+fn solved() -> Board {
+    Board::new([
+        [2, 4, 9, 7, 5, 1, 8, 6, 3],
+        [1, 3, 5, 8, 6, 9, 7, 4, 2],
+        [8, 6, 7, 2, 4, 3, 9, 5, 1],
+        [7, 8, 6, 3, 1, 2, 4, 9, 5],
+        [5, 2, 3, 9, 8, 4, 6, 1, 7],
+        [9, 1, 4, 5, 7, 6, 2, 3, 8],
+        [4, 7, 1, 6, 2, 5, 3, 8, 9],
+        [6, 9, 8, 1, 3, 7, 5, 2, 4],
+        [3, 5, 2, 4, 9, 8, 1, 7, 6],
+    ])
+    .expect("building board literal")
+}
+
+#[test]
+fn check_rotate90() {
+    let board = solved();
+    let rotated = board.rotate90();
+    rotated.check().expect("Failed to validate board.");
+    assert!(rotated.is_complete());
+
+    // The left column, read bottom to top, becomes the top row.
+    assert_eq!(
+        rotated,
+        Board::new([
+            [3, 6, 4, 9, 5, 7, 8, 1, 2],
+            [5, 9, 7, 1, 2, 8, 6, 3, 4],
+            [2, 8, 1, 4, 3, 6, 7, 5, 9],
+            [4, 1, 6, 5, 9, 3, 2, 8, 7],
+            [9, 3, 2, 7, 8, 1, 4, 6, 5],
+            [8, 7, 5, 6, 4, 2, 3, 9, 1],
+            [1, 5, 3, 2, 6, 4, 9, 7, 8],
+            [7, 2, 8, 3, 1, 9, 5, 4, 6],
+            [6, 4, 9, 8, 7, 5, 1, 2, 3],
+        ])
+        .expect("building board literal")
+    );
+
+    // Four quarter turns bring us back where we started.
+    assert_eq!(rotated.rotate90().rotate90().rotate90(), board);
+}
+
+#[test]
+fn check_transpose() {
+    let board = solved();
+    let transposed = board.transpose();
+    transposed.check().expect("Failed to validate board.");
+    assert!(transposed.is_complete());
+    assert_eq!(transposed.cells[0][1], board.cells[1][0]);
+    assert_eq!(transposed.transpose(), board);
+}
+
+#[test]
+fn check_mirror_horizontal() {
+    let board = solved();
+    let mirrored = board.mirror_horizontal();
+    mirrored.check().expect("Failed to validate board.");
+    assert!(mirrored.is_complete());
+    assert_eq!(mirrored.cells[0][0], board.cells[0][8]);
+    assert_eq!(mirrored.mirror_horizontal(), board);
+}
+
+#[test]
+fn check_transform_givens() {
+    let mut board = Board::new([
+        [1, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0],
+    ])
+    .expect("building board literal")
+    .rotate90();
+
+    // The given in the top-left corner moves to the top-right, and stays a given.
+    assert!(board.is_given(0, 8));
+    assert!(!board.is_given(0, 0));
+    board.reset();
+    assert_eq!(board.cells[0][8], Cell::Value(1));
+}
+
+#[test]
+fn check_canonicalize() {
+    let board = Board::new([
+        [0, 0, 5, 3, 0, 0, 0, 0, 0],
+        [8, 0, 0, 0, 0, 0, 0, 2, 0],
+        [0, 7, 0, 0, 1, 0, 5, 0, 0],
+        [4, 0, 0, 0, 0, 5, 3, 0, 0],
+        [0, 1, 0, 0, 7, 0, 0, 0, 6],
+        [0, 0, 3, 2, 0, 0, 0, 8, 0],
+        [0, 6, 0, 5, 0, 0, 0, 0, 9],
+        [0, 0, 4, 0, 0, 0, 0, 3, 0],
+        [0, 0, 0, 0, 0, 9, 7, 0, 0],
+    ])
+    .expect("building board literal");
+
+    // The same puzzle, with each value v replaced by 10 - v.
+    let twin = Board::new([
+        [0, 0, 5, 7, 0, 0, 0, 0, 0],
+        [2, 0, 0, 0, 0, 0, 0, 8, 0],
+        [0, 3, 0, 0, 9, 0, 5, 0, 0],
+        [6, 0, 0, 0, 0, 5, 7, 0, 0],
+        [0, 9, 0, 0, 3, 0, 0, 0, 4],
+        [0, 0, 7, 8, 0, 0, 0, 2, 0],
+        [0, 4, 0, 5, 0, 0, 0, 0, 1],
+        [0, 0, 6, 0, 0, 0, 0, 7, 0],
+        [0, 0, 0, 0, 0, 1, 3, 0, 0],
+    ])
+    .expect("building board literal");
+    assert_ne!(board, twin);
+
+    let canonical = board.canonicalize();
+    canonical.check().expect("Failed to validate board.");
+    assert_eq!(canonical.count_filled(), board.count_filled());
+    assert_eq!(twin.canonicalize(), canonical);
+
+    // Symmetries of the puzzle canonicalize the same way too.
+    assert_eq!(board.rotate90().canonicalize(), canonical);
+    assert_eq!(twin.transpose().canonicalize(), canonical);
+    assert_eq!(board.mirror_horizontal().canonicalize(), canonical);
+}
@@ -0,0 +1,63 @@
+use super::bitset::BitSet;
+use super::{Board, Cell};
+
+// One of the rows, columns, or regions (boxes, on a standard board) that must each hold the
+// values 1 to N exactly once, by index.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Unit {
+    Row(usize),
+    Col(usize),
+    Box(usize), // Numbered as for Board::region.
+}
+
+impl<const N: usize> Board<N> {
+    // The (row, column) of the index-th cell of the given unit.
+    pub fn unit_cell(&self, unit: Unit, index: usize) -> (usize, usize) {
+        match unit {
+            Unit::Row(ridx) => (ridx, index),
+            Unit::Col(cidx) => (index, cidx),
+            Unit::Box(region) => self.regions.cell(region, index),
+        }
+    }
+
+    // The cells of the given unit, in the order of unit_cell.
+    pub fn unit_cells(&self, unit: Unit) -> [Cell; N] {
+        let mut cells = [Cell::Value(0); N];
+        for (index, cell) in cells.iter_mut().enumerate() {
+            let (ridx, cidx) = self.unit_cell(unit, index);
+            *cell = self.cells[ridx][cidx];
+        }
+        cells
+    }
+
+    // The cells of each row, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = [Cell; N]> + '_ {
+        (0..N).map(move |idx| self.unit_cells(Unit::Row(idx)))
+    }
+
+    // The cells of each column, from left to right.
+    pub fn cols(&self) -> impl Iterator<Item = [Cell; N]> + '_ {
+        (0..N).map(move |idx| self.unit_cells(Unit::Col(idx)))
+    }
+
+    // The cells of each box (or region), numbered as for Board::region.
+    pub fn boxes(&self) -> impl Iterator<Item = [Cell; N]> + '_ {
+        (0..N).map(move |idx| self.unit_cells(Unit::Box(idx)))
+    }
+
+    // For each value (at index value-1), the positions within the unit (as for unit_cell) of
+    // the unsolved cells that still have it as an option.
+    pub fn candidate_positions(&self, unit: Unit) -> [BitSet; N] {
+        let mut positions = [BitSet::new(&[]); N];
+        for index in 0..N {
+            let (ridx, cidx) = self.unit_cell(unit, index);
+            for value in self.options(ridx, cidx).foreach() {
+                positions[value - 1] = positions[value - 1].set(index);
+            }
+        }
+        positions
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
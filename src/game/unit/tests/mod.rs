@@ -0,0 +1,80 @@
+use super::*;
+use crate::game::Cell;
+
+fn puzzle() -> Board {
+    Board::<9>::parse_line(
+        "005300000800000020070010500400005300010070006003200080060500009004000030000009700",
+    )
+    .expect("building board line")
+}
+
+#[test]
+fn check_unit_cell() {
+    let board = puzzle();
+    assert_eq!(board.unit_cell(Unit::Row(4), 7), (4, 7));
+    assert_eq!(board.unit_cell(Unit::Col(4), 7), (7, 4));
+    // Box 5 is the middle right subsquare.
+    assert_eq!(board.unit_cell(Unit::Box(5), 0), (3, 6));
+    assert_eq!(board.unit_cell(Unit::Box(5), 4), (4, 7));
+    assert_eq!(board.unit_cell(Unit::Box(5), 8), (5, 8));
+}
+
+#[test]
+fn check_candidate_positions() {
+    let board = puzzle();
+
+    // Row 0 is 005300000.  The 8 in row 1 column 0 rules out 8 for the top left subsquare,
+    // and the 8 in row 5 column 7 rules it out for column 7.  That leaves 4, 5, 6, and 8.
+    let positions = board.candidate_positions(Unit::Row(0));
+    assert_eq!(positions[8 - 1], BitSet::new(&[4, 5, 6, 8]));
+    // The values already in the row are possible nowhere.
+    assert!(positions[5 - 1].empty());
+    assert!(positions[3 - 1].empty());
+
+    // The column and box views agree with the row on the cells they share.
+    for value in 1..=9 {
+        let in_row = positions[value - 1].has(4);
+        let in_col = board.candidate_positions(Unit::Col(4))[value - 1].has(0);
+        let in_box = board.candidate_positions(Unit::Box(1))[value - 1].has(1);
+        assert_eq!(in_row, in_col);
+        assert_eq!(in_row, in_box);
+    }
+}
+
+#[test]
+fn check_units() {
+    let board = puzzle();
+
+    // The rows put back together are the board.
+    let rows: Vec<[Cell; 9]> = board.rows().collect();
+    assert_eq!(rows, board.cells.to_vec());
+
+    // The columns are the rows transposed.
+    for (cidx, col) in board.cols().enumerate() {
+        for (ridx, &cell) in col.iter().enumerate() {
+            assert_eq!(cell, board.cells[ridx][cidx]);
+        }
+    }
+
+    // Each box holds the cells of its subsquare, left to right and top to bottom.
+    let boxes: Vec<[Cell; 9]> = board.boxes().collect();
+    assert_eq!(boxes.len(), 9);
+    assert_eq!(boxes[0][2], Cell::Value(5));
+    assert_eq!(boxes[0][3], Cell::Value(8));
+    assert_eq!(boxes[8][6], Cell::Value(7));
+
+    // Every unit of a valid board holds each value at most once.
+    for unit in board.rows().chain(board.cols()).chain(board.boxes()) {
+        let values: Vec<usize> = unit
+            .iter()
+            .filter_map(|cell| match cell {
+                Cell::Value(v) => Some(*v),
+                Cell::Options(_) => None,
+            })
+            .collect();
+        let mut distinct = values.clone();
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(distinct.len(), values.len());
+    }
+}
@@ -0,0 +1,9 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod game;
+
+// Like most of the crate's tests, these lean on std, so they only build with it.
+#[cfg(all(test, feature = "std"))]
+mod tests;
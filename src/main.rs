@@ -1,14 +1,13 @@
-mod game;
+mod cli;
 
-use std::io::{self, Read};
+use std::env;
+use std::io;
+use std::process;
 
 fn main() {
-    let mut buf = String::new();
-    io::stdin()
-        .read_to_string(&mut buf)
-        .expect("failed to read stdin");
-    let mut board = game::Board::parse(buf).expect("failed to parse board");
-    board.check().expect("the provided board is invalid");
-    board.solve().expect("unable to solve board");
-    println!("Solution: {:#?}", board);
+    // The puzzle is read from the named file, if any, or else stdin.
+    if let Err(err) = cli::run(env::args().skip(1), io::stdin(), io::stdout().lock()) {
+        eprintln!("{}", err);
+        process::exit(err.exit_code());
+    }
 }
@@ -0,0 +1,21 @@
+use std::process::Command;
+
+// This runs a whole cargo build, so it is skipped unless asked for:
+//   cargo test -- --ignored check_no_std
+#[test]
+#[ignore]
+fn check_no_std() {
+    // Build the library without std, in its own target directory so that we don't contend
+    // with the build running this test.
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--lib", "--no-default-features"])
+        .current_dir(manifest_dir)
+        .env(
+            "CARGO_TARGET_DIR",
+            format!("{}/target/no_std", manifest_dir),
+        )
+        .status()
+        .expect("running cargo");
+    assert!(status.success(), "library doesn't build without std");
+}